@@ -1,7 +1,29 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
-use shortest_path_validation::graph::Graph;
-use shortest_path_validation::dijkstra::dijkstra;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use shortest_path_validation::graph::{Graph, WeightedGraph};
+use shortest_path_validation::dijkstra::{dijkstra, DijkstraWorkspace};
 use shortest_path_validation::improved_sssp::improved_sssp;
+use shortest_path_validation::core_algorithm::{CoreAlgorithm, CoreSolver, CoreWorkspace};
+use shortest_path_validation::delta_stepping::{auto_delta, delta_stepping};
+use shortest_path_validation::csr::CsrGraph;
+use shortest_path_validation::parallel::{parallel_bellman_ford, parallel_delta_stepping, thread_pool};
+use shortest_path_validation::parallel_core::ParallelCoreAlgorithm;
+use shortest_path_validation::integer_dijkstra::{dial_dijkstra, dijkstra_with_queue, radix_heap_dijkstra, IntegerGraph};
+use shortest_path_validation::monotone_queue::BinaryHeapQueue;
+use shortest_path_validation::decrease_key_dijkstra::{dary_dijkstra, fibonacci_dijkstra, pairing_dijkstra};
+use shortest_path_validation::bidirectional::bidirectional_dijkstra;
+use shortest_path_validation::astar::{astar, EuclideanHeuristic};
+use shortest_path_validation::alt::{alt, LandmarkSelection, Landmarks};
+use shortest_path_validation::contraction_hierarchy::ContractionHierarchy;
+use shortest_path_validation::cli::GraphSpec;
+use shortest_path_validation::solver::Solver;
+use shortest_path_validation::mmap_graph::{write_binary, MmapGraph};
+use shortest_path_validation::implicit::GridGraph;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Extra graphs for `benchmark_solver_registry`: graph specs or `.gr` paths
+/// separated by `;`, e.g. `BENCH_GRAPHS="data/ny.gr;grid:rows=300,cols=300"`
+const BENCH_GRAPHS_VAR: &str = "BENCH_GRAPHS";
 
 fn benchmark_algorithms(c: &mut Criterion) {
     let sizes = vec![100, 500, 1000, 2000];
@@ -29,6 +51,14 @@ fn benchmark_algorithms(c: &mut Criterion) {
                 b.iter(|| improved_sssp(black_box(g), black_box(0)));
             }
         );
+        
+        group.bench_with_input(
+            BenchmarkId::new("DeltaStepping", format!("n={}, m={}", n, m)),
+            &graph,
+            |b, g| {
+                b.iter(|| delta_stepping(black_box(g), black_box(0)));
+            }
+        );
     }
     
     group.finish();
@@ -60,10 +90,358 @@ fn benchmark_sparse_dense(c: &mut Criterion) {
                 b.iter(|| improved_sssp(black_box(g), black_box(0)));
             }
         );
+        
+        group.bench_with_input(
+            BenchmarkId::new("DeltaStepping", format!("density={:.2}, m={}", density, m)),
+            &graph,
+            |b, g| {
+                b.iter(|| delta_stepping(black_box(g), black_box(0)));
+            }
+        );
+    }
+    
+    group.finish();
+}
+
+fn benchmark_point_to_point(c: &mut Criterion) {
+    let sizes = vec![100, 500, 1000, 2000];
+    let density = 0.1;
+    let max_weight = 100.0;
+    
+    let mut group = c.benchmark_group("point_to_point");
+    
+    for n in sizes {
+        let graph = Graph::generate_random(n, density, max_weight);
+        let reverse = graph.reverse();
+        let m = graph.m();
+        let target = n - 1;
+        
+        group.bench_with_input(
+            BenchmarkId::new("Dijkstra", format!("n={}, m={}", n, m)),
+            &graph,
+            |b, g| {
+                b.iter(|| dijkstra(black_box(g), black_box(0))[target]);
+            }
+        );
+        
+        group.bench_with_input(
+            BenchmarkId::new("Core", format!("n={}, m={}", n, m)),
+            &graph,
+            |b, g| {
+                b.iter(|| CoreSolver::new(black_box(g)).sssp(black_box(0))[target]);
+            }
+        );
+        
+        group.bench_with_input(
+            BenchmarkId::new("Bidirectional", format!("n={}, m={}", n, m)),
+            &graph,
+            |b, g| {
+                b.iter(|| bidirectional_dijkstra(black_box(g), &reverse, black_box(0), black_box(target)));
+            }
+        );
+    }
+    
+    group.finish();
+}
+
+fn benchmark_geometric(c: &mut Criterion) {
+    let sizes = vec![500, 1000, 2000];
+    
+    let mut group = c.benchmark_group("geometric_point_to_point");
+    
+    for n in sizes {
+        // Keep the expected degree around 10 as n grows
+        let radius = (10.0 / (std::f64::consts::PI * n as f64)).sqrt();
+        let graph = Graph::generate_geometric(n, radius);
+        let reverse = graph.reverse();
+        let heuristic = EuclideanHeuristic::from_graph(&graph).unwrap();
+        let landmarks = Landmarks::build(&graph, 8, LandmarkSelection::Avoid);
+        let ch = ContractionHierarchy::build(&graph);
+        let m = graph.m();
+        let target = n - 1;
+        
+        group.bench_with_input(
+            BenchmarkId::new("Dijkstra", format!("n={}, m={}", n, m)),
+            &graph,
+            |b, g| {
+                b.iter(|| dijkstra(black_box(g), black_box(0))[target]);
+            }
+        );
+        
+        group.bench_with_input(
+            BenchmarkId::new("Bidirectional", format!("n={}, m={}", n, m)),
+            &graph,
+            |b, g| {
+                b.iter(|| bidirectional_dijkstra(black_box(g), &reverse, black_box(0), black_box(target)));
+            }
+        );
+        
+        group.bench_with_input(
+            BenchmarkId::new("AStar", format!("n={}, m={}", n, m)),
+            &graph,
+            |b, g| {
+                b.iter(|| astar(black_box(g), black_box(0), black_box(target), &heuristic));
+            }
+        );
+        
+        group.bench_with_input(
+            BenchmarkId::new("ALT", format!("n={}, m={}", n, m)),
+            &graph,
+            |b, g| {
+                b.iter(|| alt(black_box(g), &landmarks, black_box(0), black_box(target)).unwrap());
+            }
+        );
+        
+        group.bench_with_input(
+            BenchmarkId::new("CH", format!("n={}, m={}", n, m)),
+            &ch,
+            |b, ch| {
+                b.iter(|| ch.query(black_box(0), black_box(target)));
+            }
+        );
+    }
+    
+    group.finish();
+}
+
+fn benchmark_parallel_scaling(c: &mut Criterion) {
+    let n = 5000;
+    let density = 0.01;
+    let max_weight = 100.0;
+    let thread_counts = vec![1, 2, 4, 8];
+    
+    let graph = Graph::generate_random(n, density, max_weight);
+    let csr = CsrGraph::from_graph(&graph);
+    let delta = auto_delta(&graph);
+    
+    let mut group = c.benchmark_group("parallel_scaling");
+    
+    for threads in thread_counts {
+        let pool = thread_pool(threads);
+        
+        group.bench_with_input(
+            BenchmarkId::new("ParallelDeltaStepping", format!("threads={}", threads)),
+            &csr,
+            |b, g| {
+                b.iter(|| pool.install(|| parallel_delta_stepping(black_box(g), black_box(0), delta)));
+            }
+        );
+        
+        group.bench_with_input(
+            BenchmarkId::new("ParallelBellmanFord", format!("threads={}", threads)),
+            &csr,
+            |b, g| {
+                b.iter(|| pool.install(|| parallel_bellman_ford(black_box(g), black_box(0)).unwrap()));
+            }
+        );
+        
+        group.bench_with_input(
+            BenchmarkId::new("ParallelCore", format!("threads={}", threads)),
+            &graph,
+            |b, g| {
+                b.iter(|| pool.install(|| ParallelCoreAlgorithm::new(black_box(g)).sssp(black_box(0))));
+            }
+        );
+    }
+    
+    group.finish();
+}
+
+fn benchmark_integer_queues(c: &mut Criterion) {
+    let sizes = vec![100, 500, 1000, 2000];
+    let density = 0.1;
+    let max_weight = 100.0;
+    
+    let mut group = c.benchmark_group("integer_queues");
+    
+    for n in sizes {
+        let graph = Graph::generate_random(n, density, max_weight);
+        let integer = IntegerGraph::quantize(&graph, 1.0);
+        let m = graph.m();
+        
+        group.bench_with_input(
+            BenchmarkId::new("BinaryHeap", format!("n={}, m={}", n, m)),
+            &integer,
+            |b, g| {
+                b.iter(|| dijkstra_with_queue::<BinaryHeapQueue>(black_box(g), black_box(0)));
+            }
+        );
+        
+        group.bench_with_input(
+            BenchmarkId::new("RadixHeap", format!("n={}, m={}", n, m)),
+            &integer,
+            |b, g| {
+                b.iter(|| radix_heap_dijkstra(black_box(g), black_box(0)));
+            }
+        );
+        
+        group.bench_with_input(
+            BenchmarkId::new("Dial", format!("n={}, m={}", n, m)),
+            &integer,
+            |b, g| {
+                b.iter(|| dial_dijkstra(black_box(g), black_box(0)));
+            }
+        );
+    }
+    
+    group.finish();
+}
+
+fn benchmark_decrease_key_baseline(c: &mut Criterion) {
+    // The paper's O(m log^(2/3) n) is measured against O(m + n log n),
+    // which needs a decrease-key heap rather than lazy deletion
+    let sizes = vec![100, 500, 1000, 2000];
+    let density = 0.1;
+    let max_weight = 100.0;
+    
+    let mut group = c.benchmark_group("decrease_key_baseline");
+    
+    for n in sizes {
+        let graph = Graph::generate_random(n, density, max_weight);
+        let m = graph.m();
+        let id = format!("n={}, m={}", n, m);
+        
+        group.bench_with_input(BenchmarkId::new("LazyBinaryHeap", &id), &graph, |b, g| {
+            b.iter(|| dijkstra(black_box(g), black_box(0)));
+        });
+        
+        group.bench_with_input(BenchmarkId::new("Fibonacci", &id), &graph, |b, g| {
+            b.iter(|| fibonacci_dijkstra(black_box(g), black_box(0)));
+        });
+        
+        group.bench_with_input(BenchmarkId::new("Pairing", &id), &graph, |b, g| {
+            b.iter(|| pairing_dijkstra(black_box(g), black_box(0)));
+        });
+        
+        group.bench_with_input(BenchmarkId::new("FourAry", &id), &graph, |b, g| {
+            b.iter(|| dary_dijkstra(black_box(g), black_box(0)));
+        });
+        
+        group.bench_with_input(BenchmarkId::new("Core", &id), &graph, |b, g| {
+            b.iter(|| CoreSolver::new(black_box(g)).sssp(black_box(0)));
+        });
+    }
+    
+    group.finish();
+}
+
+/// Every registered solver on each graph family, reported in edges per
+/// second. Only the solve is timed; `Solver::setup` runs per batch.
+fn benchmark_solver_registry(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut families = vec![
+        ("random", "n=2000,density=0.01".to_string(), Graph::generate_random_with_rng(&mut rng, 2000, 0.01, 100.0)),
+        ("grid", "45x45".to_string(), Graph::generate_grid_with_rng(&mut rng, 45, 45, 100.0)),
+        ("scale_free", "n=2000,attach=5".to_string(), Graph::generate_scale_free_with_rng(&mut rng, 2000, 5, 100.0)),
+    ];
+    
+    if let Ok(specs) = std::env::var(BENCH_GRAPHS_VAR) {
+        for spec in specs.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            let graph = spec.parse::<GraphSpec>().and_then(|g| g.build(42));
+            match graph {
+                Ok(graph) if !graph.has_negative_weights() => families.push(("loaded", spec.to_string(), graph)),
+                Ok(_) => eprintln!("WARNING: skipping {}: registry solvers need non-negative weights", spec),
+                Err(e) => eprintln!("WARNING: skipping {}: {}", spec, e),
+            }
+        }
+    }
+    
+    for (family, id, graph) in &families {
+        let mut group = c.benchmark_group(format!("solvers_{}", family));
+        group.throughput(Throughput::Elements(graph.m() as u64));
+        
+        for &solver in Solver::ALL {
+            group.bench_with_input(
+                BenchmarkId::new(solver.name(), format!("{}, m={}", id, graph.m())),
+                graph,
+                |b, g| {
+                    b.iter_batched(|| solver.setup(g), |instance| instance.solve(black_box(0)), BatchSize::LargeInput);
+                }
+            );
+        }
+        
+        group.finish();
+    }
+}
+
+fn benchmark_workspace_reuse(c: &mut Criterion) {
+    // Many sources on one graph: fresh buffers per query against a reused
+    // workspace
+    let sources: Vec<usize> = (0..32).collect();
+    
+    let mut group = c.benchmark_group("workspace_reuse");
+    
+    for &(n, density) in &[(5000, 0.0003), (5000, 0.002)] {
+        let graph = Graph::generate_random(n, density, 100.0);
+        let id = format!("n={}, m={}, {} sources", n, graph.m(), sources.len());
+        
+        group.bench_with_input(BenchmarkId::new("Dijkstra", &id), &graph, |b, g| {
+            b.iter(|| sources.iter().map(|&s| dijkstra(g, s)[n - 1]).sum::<f64>());
+        });
+        
+        group.bench_with_input(BenchmarkId::new("DijkstraWorkspace", &id), &graph, |b, g| {
+            let mut workspace = DijkstraWorkspace::new(n);
+            b.iter(|| sources.iter().map(|&s| {
+                workspace.run(g, s);
+                workspace.distance(n - 1)
+            }).sum::<f64>());
+        });
+        
+        group.bench_with_input(BenchmarkId::new("Core", &id), &graph, |b, g| {
+            b.iter(|| sources.iter().map(|&s| CoreAlgorithm::new(g.clone()).sssp(s)[n - 1]).sum::<f64>());
+        });
+        
+        group.bench_with_input(BenchmarkId::new("CoreSolver", &id), &graph, |b, g| {
+            let solver = CoreSolver::new(g);
+            b.iter(|| sources.iter().map(|&s| solver.sssp(s)[n - 1]).sum::<f64>());
+        });
+        
+        group.bench_with_input(BenchmarkId::new("CoreWorkspace", &id), &graph, |b, g| {
+            let solver = CoreSolver::new(g);
+            let mut workspace = CoreWorkspace::new(n);
+            b.iter(|| sources.iter().map(|&s| {
+                workspace.run(&solver, s);
+                workspace.distance(n - 1)
+            }).sum::<f64>());
+        });
+    }
+    
+    group.finish();
+}
+
+fn benchmark_graph_backends(c: &mut Criterion) {
+    // The same grid stored four ways, searched by the generic solvers
+    let (rows, cols) = (150, 150);
+    let implicit = GridGraph::new(rows, cols, 100.0, 1);
+    let graph = Graph::from_view(&implicit);
+    let csr = CsrGraph::from_graph(&graph);
+    let path = std::env::temp_dir().join(format!("backend_bench_{}.bin", std::process::id()));
+    write_binary(&graph, &path).unwrap();
+    let mapped = MmapGraph::open(&path).unwrap();
+    let id = format!("grid {}x{}", rows, cols);
+    
+    let mut group = c.benchmark_group("graph_backends");
+    group.throughput(Throughput::Elements(graph.m() as u64));
+    
+    for solver in [Solver::Dijkstra, Solver::Core, Solver::DeltaStepping] {
+        group.bench_function(BenchmarkId::new(format!("{}/graph", solver.name()), &id), |b| {
+            b.iter(|| solver.run_on(&graph, 0))
+        });
+        group.bench_function(BenchmarkId::new(format!("{}/csr", solver.name()), &id), |b| {
+            b.iter(|| solver.run_on(&csr, 0))
+        });
+        group.bench_function(BenchmarkId::new(format!("{}/mmap", solver.name()), &id), |b| {
+            b.iter(|| solver.run_on(&mapped, 0))
+        });
+        group.bench_function(BenchmarkId::new(format!("{}/implicit", solver.name()), &id), |b| {
+            b.iter(|| solver.run_on(&implicit, 0))
+        });
     }
     
     group.finish();
+    drop(mapped);
+    let _ = std::fs::remove_file(&path);
 }
 
-criterion_group!(benches, benchmark_algorithms, benchmark_sparse_dense);
+criterion_group!(benches, benchmark_algorithms, benchmark_sparse_dense, benchmark_point_to_point, benchmark_geometric, benchmark_parallel_scaling, benchmark_integer_queues, benchmark_decrease_key_baseline, benchmark_solver_registry, benchmark_workspace_reuse, benchmark_graph_backends);
 criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,423 @@
+/// Min-priority queue over items 0..n that supports decrease-key, as needed
+/// for Dijkstra's O(m + n log n) bound (with a Fibonacci heap).
+///
+/// All implementations are arena-based: per-item arrays indexed by the item
+/// itself, allocated once for `n` items.
+pub trait AddressableQueue {
+    fn with_capacity(n: usize) -> Self;
+    fn insert(&mut self, item: usize, key: f64);
+    /// `key` must not exceed the item's current key
+    fn decrease_key(&mut self, item: usize, key: f64);
+    fn pop_min(&mut self) -> Option<(usize, f64)>;
+    fn contains(&self, item: usize) -> bool;
+    fn is_empty(&self) -> bool;
+}
+
+const NONE: usize = usize::MAX;
+
+/// Indexed d-ary heap: an implicit d-ary tree plus a position index
+pub struct DaryHeap<const D: usize> {
+    heap: Vec<usize>,
+    key: Vec<f64>,
+    pos: Vec<usize>,
+}
+
+impl<const D: usize> DaryHeap<D> {
+    fn swap(&mut self, i: usize, j: usize) {
+        self.heap.swap(i, j);
+        self.pos[self.heap[i]] = i;
+        self.pos[self.heap[j]] = j;
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / D;
+            if self.key[self.heap[i]] >= self.key[self.heap[parent]] {
+                break;
+            }
+            self.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let first = i * D + 1;
+            if first >= self.heap.len() {
+                break;
+            }
+            let last = (first + D).min(self.heap.len());
+            let smallest = (first..last)
+                .min_by(|&a, &b| self.key[self.heap[a]].total_cmp(&self.key[self.heap[b]]))
+                .unwrap_or(first);
+            if self.key[self.heap[smallest]] >= self.key[self.heap[i]] {
+                break;
+            }
+            self.swap(i, smallest);
+            i = smallest;
+        }
+    }
+}
+
+impl<const D: usize> AddressableQueue for DaryHeap<D> {
+    fn with_capacity(n: usize) -> Self {
+        DaryHeap {
+            heap: Vec::with_capacity(n),
+            key: vec![f64::INFINITY; n],
+            pos: vec![NONE; n],
+        }
+    }
+
+    fn insert(&mut self, item: usize, key: f64) {
+        self.key[item] = key;
+        self.pos[item] = self.heap.len();
+        self.heap.push(item);
+        self.sift_up(self.heap.len() - 1);
+    }
+
+    fn decrease_key(&mut self, item: usize, key: f64) {
+        self.key[item] = key;
+        self.sift_up(self.pos[item]);
+    }
+
+    fn pop_min(&mut self) -> Option<(usize, f64)> {
+        let min = *self.heap.first()?;
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        self.heap.pop();
+        self.pos[min] = NONE;
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        Some((min, self.key[min]))
+    }
+
+    fn contains(&self, item: usize) -> bool {
+        self.pos[item] != NONE
+    }
+
+    fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
+
+/// Pairing heap with two-pass pairing on delete-min. `prev` points to the
+/// left sibling, or to the parent for a first child.
+pub struct PairingHeap {
+    key: Vec<f64>,
+    child: Vec<usize>,
+    sibling: Vec<usize>,
+    prev: Vec<usize>,
+    in_heap: Vec<bool>,
+    root: usize,
+    len: usize,
+}
+
+impl PairingHeap {
+    /// Links two detached trees, returning the new root
+    fn meld(&mut self, a: usize, b: usize) -> usize {
+        if a == NONE {
+            return b;
+        }
+        if b == NONE {
+            return a;
+        }
+        let (parent, child) = if self.key[b] < self.key[a] { (b, a) } else { (a, b) };
+
+        self.sibling[child] = self.child[parent];
+        if self.child[parent] != NONE {
+            self.prev[self.child[parent]] = child;
+        }
+        self.child[parent] = child;
+        self.prev[child] = parent;
+        parent
+    }
+}
+
+impl AddressableQueue for PairingHeap {
+    fn with_capacity(n: usize) -> Self {
+        PairingHeap {
+            key: vec![f64::INFINITY; n],
+            child: vec![NONE; n],
+            sibling: vec![NONE; n],
+            prev: vec![NONE; n],
+            in_heap: vec![false; n],
+            root: NONE,
+            len: 0,
+        }
+    }
+
+    fn insert(&mut self, item: usize, key: f64) {
+        self.key[item] = key;
+        self.child[item] = NONE;
+        self.sibling[item] = NONE;
+        self.prev[item] = NONE;
+        self.in_heap[item] = true;
+        self.root = self.meld(self.root, item);
+        self.len += 1;
+    }
+
+    fn decrease_key(&mut self, item: usize, key: f64) {
+        self.key[item] = key;
+        if item == self.root {
+            return;
+        }
+
+        // Cut the subtree rooted at item and meld it with the root
+        let p = self.prev[item];
+        if self.child[p] == item {
+            self.child[p] = self.sibling[item];
+        } else {
+            self.sibling[p] = self.sibling[item];
+        }
+        if self.sibling[item] != NONE {
+            self.prev[self.sibling[item]] = p;
+        }
+        self.sibling[item] = NONE;
+        self.prev[item] = NONE;
+        self.root = self.meld(self.root, item);
+    }
+
+    fn pop_min(&mut self) -> Option<(usize, f64)> {
+        if self.root == NONE {
+            return None;
+        }
+        let min = self.root;
+
+        let mut children = Vec::new();
+        let mut c = self.child[min];
+        while c != NONE {
+            let next = self.sibling[c];
+            self.sibling[c] = NONE;
+            self.prev[c] = NONE;
+            children.push(c);
+            c = next;
+        }
+
+        // First pass: meld pairs left to right
+        let mut paired: Vec<usize> = children
+            .chunks(2)
+            .map(|pair| if pair.len() == 2 { self.meld(pair[0], pair[1]) } else { pair[0] })
+            .collect();
+
+        // Second pass: meld right to left into one tree
+        let mut root = paired.pop().unwrap_or(NONE);
+        while let Some(tree) = paired.pop() {
+            root = self.meld(tree, root);
+        }
+
+        self.child[min] = NONE;
+        self.in_heap[min] = false;
+        self.root = root;
+        self.len -= 1;
+        Some((min, self.key[min]))
+    }
+
+    fn contains(&self, item: usize) -> bool {
+        self.in_heap[item]
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Fibonacci heap with cascading cuts. Sibling lists are circular and
+/// doubly linked through `left`/`right`.
+pub struct FibonacciHeap {
+    key: Vec<f64>,
+    parent: Vec<usize>,
+    child: Vec<usize>,
+    left: Vec<usize>,
+    right: Vec<usize>,
+    degree: Vec<usize>,
+    mark: Vec<bool>,
+    in_heap: Vec<bool>,
+    min: usize,
+    len: usize,
+}
+
+impl FibonacciHeap {
+    /// Inserts x into the circular list next to `anchor` (or alone)
+    fn splice(&mut self, anchor: usize, x: usize) {
+        if anchor == NONE {
+            self.left[x] = x;
+            self.right[x] = x;
+        } else {
+            let next = self.right[anchor];
+            self.left[x] = anchor;
+            self.right[x] = next;
+            self.left[next] = x;
+            self.right[anchor] = x;
+        }
+    }
+
+    fn unlink(&mut self, x: usize) {
+        let (l, r) = (self.left[x], self.right[x]);
+        self.right[l] = r;
+        self.left[r] = l;
+        self.left[x] = x;
+        self.right[x] = x;
+    }
+
+    fn add_root(&mut self, x: usize) {
+        self.parent[x] = NONE;
+        self.splice(self.min, x);
+        if self.min == NONE || self.key[x] < self.key[self.min] {
+            self.min = x;
+        }
+    }
+
+    /// Moves x from its parent's child list to the root list
+    fn cut(&mut self, x: usize, p: usize) {
+        if self.right[x] == x {
+            self.child[p] = NONE;
+        } else {
+            if self.child[p] == x {
+                self.child[p] = self.right[x];
+            }
+            self.unlink(x);
+        }
+        self.degree[p] -= 1;
+        self.mark[x] = false;
+        self.add_root(x);
+    }
+
+    /// Makes root y a child of root x
+    fn link(&mut self, y: usize, x: usize) {
+        self.parent[y] = x;
+        self.splice(self.child[x], y);
+        if self.child[x] == NONE {
+            self.child[x] = y;
+        }
+        self.degree[x] += 1;
+        self.mark[y] = false;
+    }
+
+    /// Merges roots of equal degree until all degrees differ
+    fn consolidate(&mut self, roots: Vec<usize>) {
+        let mut by_degree: Vec<usize> = Vec::new();
+
+        for mut x in roots {
+            let mut d = self.degree[x];
+            loop {
+                if d >= by_degree.len() {
+                    by_degree.resize(d + 1, NONE);
+                }
+                let y = by_degree[d];
+                if y == NONE {
+                    break;
+                }
+                let (winner, loser) = if self.key[y] < self.key[x] { (y, x) } else { (x, y) };
+                self.link(loser, winner);
+                x = winner;
+                by_degree[d] = NONE;
+                d += 1;
+            }
+            by_degree[d] = x;
+        }
+
+        self.min = NONE;
+        for x in by_degree.into_iter().filter(|&x| x != NONE) {
+            self.add_root(x);
+        }
+    }
+}
+
+impl AddressableQueue for FibonacciHeap {
+    fn with_capacity(n: usize) -> Self {
+        FibonacciHeap {
+            key: vec![f64::INFINITY; n],
+            parent: vec![NONE; n],
+            child: vec![NONE; n],
+            left: vec![NONE; n],
+            right: vec![NONE; n],
+            degree: vec![0; n],
+            mark: vec![false; n],
+            in_heap: vec![false; n],
+            min: NONE,
+            len: 0,
+        }
+    }
+
+    fn insert(&mut self, item: usize, key: f64) {
+        self.key[item] = key;
+        self.child[item] = NONE;
+        self.degree[item] = 0;
+        self.mark[item] = false;
+        self.in_heap[item] = true;
+        self.add_root(item);
+        self.len += 1;
+    }
+
+    fn decrease_key(&mut self, item: usize, key: f64) {
+        self.key[item] = key;
+
+        let p = self.parent[item];
+        if p != NONE && key < self.key[p] {
+            self.cut(item, p);
+
+            // Cascading cut: cut marked ancestors, mark the first unmarked one
+            let mut y = p;
+            while self.parent[y] != NONE {
+                if !self.mark[y] {
+                    self.mark[y] = true;
+                    break;
+                }
+                let z = self.parent[y];
+                self.cut(y, z);
+                y = z;
+            }
+        }
+
+        if key < self.key[self.min] {
+            self.min = item;
+        }
+    }
+
+    fn pop_min(&mut self) -> Option<(usize, f64)> {
+        if self.min == NONE {
+            return None;
+        }
+        let z = self.min;
+
+        // Remaining roots plus z's children become the new root list
+        let mut roots = Vec::new();
+        let mut r = self.right[z];
+        while r != z {
+            roots.push(r);
+            r = self.right[r];
+        }
+        let first_child = self.child[z];
+        if first_child != NONE {
+            let mut c = first_child;
+            loop {
+                roots.push(c);
+                self.parent[c] = NONE;
+                c = self.right[c];
+                if c == first_child {
+                    break;
+                }
+            }
+        }
+        for &x in &roots {
+            self.left[x] = x;
+            self.right[x] = x;
+        }
+
+        self.child[z] = NONE;
+        self.in_heap[z] = false;
+        self.len -= 1;
+        self.consolidate(roots);
+
+        Some((z, self.key[z]))
+    }
+
+    fn contains(&self, item: usize) -> bool {
+        self.in_heap[item]
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
@@ -0,0 +1,332 @@
+use crate::graph::{Graph, WeightedGraph};
+use crate::dijkstra::dijkstra;
+use crate::astar::{astar, Heuristic};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+const MAGIC: &[u8; 4] = b"ALT1";
+
+/// How landmarks are picked during preprocessing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LandmarkSelection {
+    /// Uniformly random vertices
+    Random,
+    /// Each new landmark is the reachable vertex farthest from those chosen so far
+    Farthest,
+    /// Goldberg-Harrelson "avoid": grow a shortest-path tree from a random
+    /// root and descend into the subtree whose vertices are worst covered
+    Avoid,
+}
+
+/// Precomputed landmark distances for ALT queries.
+///
+/// Distances are stored vertex-major in flat arrays, so all `k` entries
+/// needed to bound one vertex are contiguous.
+#[derive(Debug, Clone)]
+pub struct Landmarks {
+    pub landmarks: Vec<usize>,
+    n: usize,
+    /// from[v * k + i] = d(landmarks[i], v)
+    from: Vec<f64>,
+    /// to[v * k + i] = d(v, landmarks[i])
+    to: Vec<f64>,
+}
+
+impl Landmarks {
+    pub fn build<G: WeightedGraph>(graph: &G, count: usize, selection: LandmarkSelection) -> Self {
+        let count = count.min(graph.vertex_count());
+        match selection {
+            LandmarkSelection::Random => Landmarks::from_landmarks(graph, select_random(graph, count)),
+            LandmarkSelection::Farthest => Landmarks::from_landmarks(graph, select_farthest(graph, count)),
+            // Avoid needs the partial tables while selecting, so it builds them itself
+            LandmarkSelection::Avoid => select_avoid(graph, count),
+        }
+    }
+
+    /// Computes the distance tables for an explicit landmark set
+    pub fn from_landmarks<G: WeightedGraph>(graph: &G, landmarks: Vec<usize>) -> Self {
+        let reverse = Graph::from_reverse(graph);
+        let mut table = Landmarks::empty(graph.vertex_count());
+        for l in landmarks {
+            table.push(l, &dijkstra(graph, l), &dijkstra(&reverse, l));
+        }
+        table
+    }
+
+    fn empty(n: usize) -> Self {
+        Landmarks {
+            landmarks: Vec::new(),
+            n,
+            from: Vec::new(),
+            to: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.landmarks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.landmarks.is_empty()
+    }
+
+    /// Lower bound on d(v, target) from the triangle inequality:
+    /// d(L,t) - d(L,v) and d(v,L) - d(t,L) for every landmark L. Infinite
+    /// when a landmark shows target is unreachable from v (L reaches v but
+    /// not t, or t reaches L but v does not), which keeps the bound
+    /// consistent across edges into such vertices.
+    pub fn lower_bound(&self, v: usize, target: usize) -> f64 {
+        let k = self.landmarks.len();
+        let (from_v, from_t) = (&self.from[v * k..(v + 1) * k], &self.from[target * k..(target + 1) * k]);
+        let (to_v, to_t) = (&self.to[v * k..(v + 1) * k], &self.to[target * k..(target + 1) * k]);
+
+        let mut bound = 0.0f64;
+        for i in 0..k {
+            if from_v[i].is_finite() {
+                bound = bound.max(from_t[i] - from_v[i]);
+            }
+            if to_t[i].is_finite() {
+                bound = bound.max(to_v[i] - to_t[i]);
+            }
+        }
+        bound
+    }
+
+    /// Writes the tables in a little-endian binary format
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        out.write_all(MAGIC)?;
+        out.write_all(&(self.n as u64).to_le_bytes())?;
+        out.write_all(&(self.landmarks.len() as u64).to_le_bytes())?;
+        for &l in &self.landmarks {
+            out.write_all(&(l as u64).to_le_bytes())?;
+        }
+        for &d in self.from.iter().chain(self.to.iter()) {
+            out.write_all(&d.to_le_bytes())?;
+        }
+        out.flush()
+    }
+
+    /// Reads tables written by `save`. The header is checked against the
+    /// file length before anything is allocated, and every landmark must be
+    /// a vertex below n.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let len = file.metadata()?.len();
+        let mut input = BufReader::new(file);
+        let mut magic = [0u8; 4];
+        input.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid("not a landmark table".to_string()));
+        }
+
+        let n = read_u64(&mut input)? as usize;
+        let k = read_u64(&mut input)? as usize;
+        let entries = n.checked_mul(k);
+        let expected = entries
+            .and_then(|entries| entries.checked_mul(2))
+            .and_then(|words| words.checked_add(k))
+            .and_then(|words| words.checked_mul(8))
+            .and_then(|bytes| bytes.checked_add(MAGIC.len() + 16));
+        if expected.map(|bytes| bytes as u64) != Some(len) {
+            return Err(invalid(format!("file has {} bytes, expected {:?} for n={} k={}", len, expected, n, k)));
+        }
+        let entries = entries.unwrap_or_default();
+
+        let landmarks = (0..k).map(|_| read_u64(&mut input).map(|l| l as usize)).collect::<io::Result<Vec<_>>>()?;
+        if let Some(&l) = landmarks.iter().find(|&&l| l >= n) {
+            return Err(invalid(format!("landmark {} is not a vertex of {}", l, n)));
+        }
+        let from = (0..entries).map(|_| read_f64(&mut input)).collect::<io::Result<Vec<_>>>()?;
+        let to = (0..entries).map(|_| read_f64(&mut input)).collect::<io::Result<Vec<_>>>()?;
+
+        Ok(Landmarks { landmarks, n, from, to })
+    }
+
+    /// Number of vertices the tables were built for
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Checks that the tables fit `graph`, e.g. after `load`. Bounds for a
+    /// graph of another size would index past the tables.
+    pub fn validate_for<G: WeightedGraph>(&self, graph: &G) -> Result<(), SizeMismatch> {
+        if self.n == graph.vertex_count() {
+            Ok(())
+        } else {
+            Err(SizeMismatch { landmarks: self.n, graph: graph.vertex_count() })
+        }
+    }
+
+    fn push(&mut self, landmark: usize, from: &[f64], to: &[f64]) {
+        let k = self.landmarks.len();
+        let mut new_from = Vec::with_capacity(self.n * (k + 1));
+        let mut new_to = Vec::with_capacity(self.n * (k + 1));
+        for v in 0..self.n {
+            new_from.extend_from_slice(&self.from[v * k..(v + 1) * k]);
+            new_from.push(from[v]);
+            new_to.extend_from_slice(&self.to[v * k..(v + 1) * k]);
+            new_to.push(to[v]);
+        }
+        self.landmarks.push(landmark);
+        self.from = new_from;
+        self.to = new_to;
+    }
+}
+
+impl Heuristic for Landmarks {
+    fn estimate(&self, v: usize, target: usize) -> f64 {
+        self.lower_bound(v, target)
+    }
+}
+
+/// A landmark table used with a graph it was not built for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeMismatch {
+    pub landmarks: usize,
+    pub graph: usize,
+}
+
+impl fmt::Display for SizeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "landmark table is for {} vertices, graph has {}", self.landmarks, self.graph)
+    }
+}
+
+impl std::error::Error for SizeMismatch {}
+
+/// ALT point-to-point query: A* guided by landmark lower bounds. Fails if
+/// `landmarks` was built for a graph of another size.
+pub fn alt<G: WeightedGraph>(graph: &G, landmarks: &Landmarks, source: usize, target: usize) -> Result<f64, SizeMismatch> {
+    landmarks.validate_for(graph)?;
+    Ok(astar(graph, source, target, landmarks))
+}
+
+fn select_random<G: WeightedGraph>(graph: &G, count: usize) -> Vec<usize> {
+    let mut vertices: Vec<usize> = (0..graph.vertex_count()).collect();
+    vertices.shuffle(&mut rand::thread_rng());
+    vertices.truncate(count);
+    vertices
+}
+
+fn select_farthest<G: WeightedGraph>(graph: &G, count: usize) -> Vec<usize> {
+    let n = graph.vertex_count();
+    let mut rng = rand::thread_rng();
+    let mut landmarks = Vec::new();
+    if count == 0 {
+        return landmarks;
+    }
+
+    // Distance from the nearest landmark chosen so far
+    let mut nearest = vec![f64::INFINITY; n];
+    let mut next = rng.gen_range(0..n);
+
+    while landmarks.len() < count {
+        landmarks.push(next);
+        for (v, d) in dijkstra(graph, next).into_iter().enumerate() {
+            nearest[v] = nearest[v].min(d);
+        }
+
+        let farthest = (0..n)
+            .filter(|&v| nearest[v].is_finite() && nearest[v] > 0.0)
+            .max_by(|&a, &b| nearest[a].total_cmp(&nearest[b]));
+
+        next = match farthest {
+            Some(v) => v,
+            // Everything reachable is covered, start again somewhere unreached
+            None => match (0..n)
+                .find(|&v| nearest[v].is_infinite())
+                .or_else(|| (0..n).find(|v| !landmarks.contains(v)))
+            {
+                Some(v) => v,
+                None => break,
+            },
+        };
+    }
+
+    landmarks
+}
+
+fn select_avoid<G: WeightedGraph>(graph: &G, count: usize) -> Landmarks {
+    let n = graph.vertex_count();
+    let mut rng = rand::thread_rng();
+    let reverse = Graph::from_reverse(graph);
+    let mut table = Landmarks::empty(n);
+
+    while table.len() < count {
+        let root = rng.gen_range(0..n);
+        let dist = dijkstra(graph, root);
+
+        // Shortest-path tree from the root
+        let mut children = vec![Vec::new(); n];
+        let mut has_parent = vec![false; n];
+        for u in 0..n {
+            for (v, weight) in graph.out_neighbors(u) {
+                if v != root && !has_parent[v] && dist[u].is_finite() && dist[u] + weight == dist[v] {
+                    has_parent[v] = true;
+                    children[u].push(v);
+                }
+            }
+        }
+
+        let mut order = vec![root];
+        let mut i = 0;
+        while i < order.len() {
+            order.extend_from_slice(&children[order[i]]);
+            i += 1;
+        }
+
+        // size(v): total bound slack in v's subtree, 0 if it holds a landmark
+        let mut size = vec![0.0f64; n];
+        let mut covered = vec![false; n];
+        for &v in order.iter().rev() {
+            covered[v] = table.landmarks.contains(&v) || children[v].iter().any(|&c| covered[c]);
+            if !covered[v] {
+                let slack = dist[v] - table.lower_bound(root, v);
+                size[v] = slack + children[v].iter().map(|&c| size[c]).sum::<f64>();
+            }
+        }
+
+        // Descend towards the largest uncovered subtree and take its leaf
+        let mut v = root;
+        while let Some(&c) = children[v]
+            .iter()
+            .filter(|&&c| size[c] > 0.0)
+            .max_by(|&&a, &&b| size[a].total_cmp(&size[b]))
+        {
+            v = c;
+        }
+
+        let landmark = if table.landmarks.contains(&v) {
+            match (0..n).find(|u| !table.landmarks.contains(u)) {
+                Some(u) => u,
+                None => break,
+            }
+        } else {
+            v
+        };
+        table.push(landmark, &dijkstra(graph, landmark), &dijkstra(&reverse, landmark));
+    }
+
+    table
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn read_u64<R: Read>(input: &mut R) -> io::Result<u64> {
+    let mut buf = [0u8; 8];
+    input.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn read_f64<R: Read>(input: &mut R) -> io::Result<f64> {
+    let mut buf = [0u8; 8];
+    input.read_exact(&mut buf)?;
+    Ok(f64::from_le_bytes(buf))
+}
@@ -1,109 +1,352 @@
-mod graph;
-mod dijkstra;
-mod improved_sssp;
-mod improved_sssp_v2;
-
-use graph::Graph;
-use dijkstra::dijkstra;
-use improved_sssp::improved_sssp;
-use improved_sssp_v2::improved_sssp_v2;
+use shortest_path_validation::graph::{Graph, WeightedGraph};
+use shortest_path_validation::dijkstra::{dijkstra, dijkstra_with_counts, OperationCounts};
+use shortest_path_validation::improved_sssp::improved_sssp;
+use shortest_path_validation::improved_sssp_v2::{improved_sssp_v2, improved_sssp_v2_with_counts};
+use shortest_path_validation::csr::CsrGraph;
+use shortest_path_validation::delta_stepping::auto_delta;
+use shortest_path_validation::parallel::{parallel_bellman_ford, parallel_delta_stepping, thread_pool};
+use shortest_path_validation::scc::{condensation_sssp_with, Condensation};
+use shortest_path_validation::cli::{open_renderer, select_sources, GraphSpec, Options};
+use shortest_path_validation::report::{Format, Record, Renderer};
+use shortest_path_validation::stats::Summary;
+use shortest_path_validation::regression::{Model, Ranking, Sample};
+use std::str::FromStr;
 use std::time::Instant;
 
-fn main() {
-    println!("Detailed Performance Analysis\n");
-    println!("{}", "=".repeat(100));
-    
-    // Test sparse graphs
-    println!("\n1. SPARSE GRAPHS (density = 0.01)");
-    println!("{}", "-".repeat(100));
-    test_density(0.01);
-    
-    // Test medium density graphs
-    println!("\n2. MEDIUM DENSITY GRAPHS (density = 0.05)");
-    println!("{}", "-".repeat(100));
-    test_density(0.05);
-    
-    // Test dense graphs
-    println!("\n3. DENSE GRAPHS (density = 0.2)");
-    println!("{}", "-".repeat(100));
-    test_density(0.2);
-    
+// Defaults for the `analyze` options
+const SIZES: [usize; 7] = [100, 500, 1000, 2000, 3000, 4000, 5000];
+const DENSITIES: [f64; 3] = [0.01, 0.05, 0.2];
+const SCALING_SIZES: [usize; 5] = [500, 1000, 2000, 4000, 8000];
+const SCALING_DENSITY: f64 = 0.05;
+const THREADS: [usize; 4] = [1, 2, 4, 8];
+const PARALLEL_SIZES: [usize; 2] = [2000, 5000];
+const PARALLEL_DENSITY: f64 = 0.01;
+const SCC_N: usize = 5000;
+const SCC_DENSITIES: [f64; 5] = [0.0002, 0.0005, 0.001, 0.002, 0.01];
+
+/// Where measurements go: the tables printed below by default, or one
+/// record per timed run with `--format json|csv`. Also carries how each
+/// time is measured.
+struct Output {
+    records: Option<Box<dyn Renderer>>,
+    seed: u64,
+    /// Every reported time is the median over `sources` random sources
+    /// times `reps` runs, each source warmed up `warmup` times first
+    sources: usize,
+    reps: usize,
+    warmup: usize,
+}
+
+impl Output {
+    fn tables(&self) -> bool {
+        self.records.is_none()
+    }
+
+    /// Prints a table line, only in table mode
+    fn line(&self, text: String) {
+        if self.tables() {
+            println!("{}", text);
+        }
+    }
+
+    fn record(&mut self, record: Record) -> Result<(), String> {
+        match self.records.as_mut() {
+            Some(records) => records.record(&record).map_err(|e| e.to_string()),
+            None => Ok(()),
+        }
+    }
+
+    /// Prints a numbered section heading
+    fn section(&self, number: &mut usize, title: String) {
+        *number += 1;
+        self.line(format!("\n{}. {}", number, title));
+        self.line("-".repeat(100));
+    }
+
+    fn sources(&self, n: usize) -> Vec<usize> {
+        select_sources(&[], self.sources, n, self.seed).unwrap_or_default()
+    }
+}
+
+/// Times `f` from each random source as described on `Output`, records
+/// every timed run under `solver` and returns the median time in ms
+fn measure<T>(out: &mut Output, id: &str, graph: &Graph, solver: &str,
+              counters: &[(&str, u64)], mut f: impl FnMut(usize) -> T) -> Result<f64, String> {
+    let mut times = Vec::new();
+
+    for s in out.sources(graph.n) {
+        for _ in 0..out.warmup {
+            let _ = f(s);
+        }
+        for rep in 0..out.reps {
+            let start = Instant::now();
+            let _ = f(s);
+            let time = start.elapsed().as_secs_f64() * 1000.0;
+            times.push(time);
+
+            let mut record = Record::new(id, graph, solver, s, time);
+            record.rep = rep;
+            record.counters = counters.iter().map(|&(name, v)| (name.to_string(), v)).collect();
+            out.record(record)?;
+        }
+    }
+
+    Ok(Summary::of(&times).map_or(0.0, |t| t.median))
+}
+
+/// Seeded random graph and its id
+fn random_graph(out: &Output, n: usize, density: f64) -> Result<(String, Graph), String> {
+    let spec = GraphSpec::Random { n, density, max_weight: 100.0 };
+    Ok((spec.to_string(), spec.build(out.seed)?))
+}
+
+/// The `analyze` command: timing tables over random graphs of each density
+/// (or the --graph inputs), complexity fits, parallel scaling and component
+/// structure. Every size and density is an option, defaulting to the
+/// constants above.
+pub fn analyze(options: &Options) -> Result<bool, String> {
+    options.check_known(&["graph", "seed", "random-sources", "reps", "warmup", "format", "output",
+                          "sizes", "densities", "scaling-sizes", "scaling-density", "threads",
+                          "parallel-sizes", "scc-n", "scc-densities"])?;
+    let graphs: Vec<GraphSpec> = options.get_all("graph").into_iter().map(str::parse).collect::<Result<_, _>>()?;
+    let densities = list_or(options, "densities", &DENSITIES)?;
+    let sizes = list_or(options, "sizes", &SIZES)?;
+    let scaling_sizes = list_or(options, "scaling-sizes", &SCALING_SIZES)?;
+    let scaling_density = options.parse_or("scaling-density", SCALING_DENSITY)?;
+    let threads = list_or(options, "threads", &THREADS)?;
+    let parallel_sizes = list_or(options, "parallel-sizes", &PARALLEL_SIZES)?;
+    let scc_n = options.parse_or("scc-n", SCC_N)?;
+    let scc_densities = list_or(options, "scc-densities", &SCC_DENSITIES)?;
+
+    let table = Format::from_name(options.get("format").unwrap_or("table")) == Some(Format::Table);
+    let mut out = Output {
+        records: if table { None } else { Some(open_renderer(options)?) },
+        seed: options.parse_or("seed", 1u64)?,
+        sources: options.parse_or("random-sources", 3usize)?.max(1),
+        reps: options.parse_or("reps", 3usize)?.max(1),
+        warmup: options.parse_or("warmup", 1usize)?,
+    };
+
+    out.line("Detailed Performance Analysis\n".to_string());
+    out.line(format!("Times are medians over {} random sources x {} runs, after {} warm-up run(s) per source",
+                     out.sources, out.reps, out.warmup));
+    out.line("=".repeat(100));
+    let mut number = 0;
+
+    if graphs.is_empty() {
+        for &density in &densities {
+            out.section(&mut number, format!("RANDOM GRAPHS (density = {})", density));
+            test_density(&mut out, &sizes, density)?;
+        }
+    } else {
+        out.section(&mut number, "GIVEN GRAPHS".to_string());
+        test_graphs(&mut out, &graphs)?;
+    }
+
     // Test complexity scaling
-    println!("\n4. COMPLEXITY SCALING ANALYSIS");
-    println!("{}", "-".repeat(100));
-    complexity_analysis();
+    out.section(&mut number, "COMPLEXITY SCALING ANALYSIS".to_string());
+    complexity_analysis(&mut out, &scaling_sizes, scaling_density)?;
+
+    // Test thread scaling of the parallel solvers
+    out.section(&mut number, "PARALLEL SCALING".to_string());
+    parallel_scaling(&mut out, &parallel_sizes, &threads)?;
+
+    // Component structure and condensation-based SSSP
+    out.section(&mut number, "STRONGLY CONNECTED COMPONENTS".to_string());
+    component_structure(&mut out, scc_n, &scc_densities)?;
+
+    if let Some(mut records) = out.records.take() {
+        records.finish().map_err(|e| e.to_string())?;
+    }
+    Ok(true)
 }
 
-fn test_density(density: f64) {
-    println!("{:<10} {:<10} {:<15} {:<15} {:<15} {:<10}", 
-             "Nodes", "Edges", "Dijkstra (ms)", "Improved (ms)", "ImprovedV2 (ms)", "Best Speedup");
-    
-    let sizes = vec![100, 500, 1000, 2000, 3000, 4000, 5000];
-    
-    for n in sizes {
-        let graph = Graph::generate_random(n, density, 100.0);
-        let m = graph.m();
-        
-        let (d_time, i1_time, i2_time) = benchmark_algorithms(&graph);
-        let best_improved = i1_time.min(i2_time);
-        let speedup = d_time / best_improved;
-        
-        println!("{:<10} {:<10} {:<15.3} {:<15.3} {:<15.3} {:<10.2}x", 
-                 n, m, d_time, i1_time, i2_time, speedup);
+/// Parsed list option, or `default` when it is not given
+fn list_or<T: FromStr + Clone>(options: &Options, name: &str, default: &[T]) -> Result<Vec<T>, String> {
+    let values = options.parse_list(name)?;
+    Ok(if values.is_empty() { default.to_vec() } else { values })
+}
+
+/// Header of a Dijkstra vs improved table whose first column is `width` wide
+fn speedup_header(out: &Output, first: &str, width: usize) {
+    out.line(format!("{:<width$} {:<10} {:<15} {:<15} {:<15} {:<10}",
+             first, "Edges", "Dijkstra (ms)", "Improved (ms)", "ImprovedV2 (ms)", "Best Speedup"));
+}
+
+fn speedup_row(out: &mut Output, label: String, width: usize, graph: &Graph, id: &str) -> Result<(), String> {
+    let (d_time, i1_time, i2_time) = benchmark_algorithms(out, graph, id)?;
+    let speedup = d_time / i1_time.min(i2_time);
+
+    out.line(format!("{:<width$} {:<10} {:<15.3} {:<15.3} {:<15.3} {:<10.2}x",
+             label, graph.m(), d_time, i1_time, i2_time, speedup));
+    Ok(())
+}
+
+fn test_density(out: &mut Output, sizes: &[usize], density: f64) -> Result<(), String> {
+    speedup_header(out, "Nodes", 10);
+    for &n in sizes {
+        let (id, graph) = random_graph(out, n, density)?;
+        speedup_row(out, n.to_string(), 10, &graph, &id)?;
     }
+    Ok(())
 }
 
-fn complexity_analysis() {
-    println!("Testing theoretical complexity O(m log^(2/3) n) vs O(m log n)");
-    println!();
-    println!("{:<10} {:<10} {:<20} {:<20} {:<15}", 
-             "Nodes", "Edges", "Dijkstra/m*log(n)", "ImprovedV2/m*log^⅔(n)", "Ratio");
-    
-    let density = 0.05;
-    let sizes = vec![500, 1000, 2000, 4000, 8000];
-    
-    for n in sizes {
-        let graph = Graph::generate_random(n, density, 100.0);
-        let m = graph.m();
-        
-        let (d_time, _, i2_time) = benchmark_algorithms(&graph);
-        
-        let log_n = (n as f64).ln();
-        let log_2_3_n = log_n.powf(2.0 / 3.0);
-        
-        let dijkstra_normalized = d_time / (m as f64 * log_n);
-        let improved_normalized = i2_time / (m as f64 * log_2_3_n);
-        let ratio = dijkstra_normalized / improved_normalized;
-        
-        println!("{:<10} {:<10} {:<20.6} {:<20.6} {:<15.3}", 
-                 n, m, dijkstra_normalized, improved_normalized, ratio);
+fn test_graphs(out: &mut Output, specs: &[GraphSpec]) -> Result<(), String> {
+    let mut graphs = Vec::new();
+    for spec in specs {
+        let graph = spec.build(out.seed)?;
+        if graph.has_negative_weights() {
+            eprintln!("Skipping {}: the compared solvers need non-negative weights", spec);
+            continue;
+        }
+        graphs.push((format!("{} ({})", spec, graph.n), spec.to_string(), graph));
     }
+
+    let width = graphs.iter().map(|(label, _, _)| label.len()).max().unwrap_or(0).max(15);
+    speedup_header(out, "Graph (nodes)", width);
+    for (label, id, graph) in graphs {
+        speedup_row(out, label, width, &graph, &id)?;
+    }
+    Ok(())
 }
 
-fn benchmark_algorithms(graph: &Graph) -> (f64, f64, f64) {
-    // Run multiple times for accuracy
-    let runs = 3;
+// A claimed model counts as supported when its R² is this close to the best
+const FIT_MARGIN: f64 = 0.01;
+
+fn complexity_analysis(out: &mut Output, sizes: &[usize], density: f64) -> Result<(), String> {
+    out.line("Fitting time and operation counts against O(m log n), O(m log^(2/3) n) and O(m + n log n)".to_string());
+    out.line(format!("Random graphs with density {}", density));
+    out.line(String::new());
+    out.line(format!("{:<10} {:<10} {:<15} {:<15} {:<15} {:<15}",
+             "Nodes", "Edges", "Dijkstra (ms)", "ImprovedV2 (ms)", "Dijkstra ops", "ImprovedV2 ops"));
+
     let mut d_times = Vec::new();
-    let mut i1_times = Vec::new();
     let mut i2_times = Vec::new();
-    
-    for _ in 0..runs {
-        let start = Instant::now();
-        let _ = dijkstra(&graph, 0);
-        d_times.push(start.elapsed().as_secs_f64() * 1000.0);
-        
-        let start = Instant::now();
-        let _ = improved_sssp(&graph, 0);
-        i1_times.push(start.elapsed().as_secs_f64() * 1000.0);
-        
-        let start = Instant::now();
-        let _ = improved_sssp_v2(&graph, 0);
-        i2_times.push(start.elapsed().as_secs_f64() * 1000.0);
-    }
-    
-    let d_avg = d_times.iter().sum::<f64>() / runs as f64;
-    let i1_avg = i1_times.iter().sum::<f64>() / runs as f64;
-    let i2_avg = i2_times.iter().sum::<f64>() / runs as f64;
-    
-    (d_avg, i1_avg, i2_avg)
-}
\ No newline at end of file
+    let mut d_ops = Vec::new();
+    let mut i2_ops = Vec::new();
+
+    for &n in sizes {
+        let (id, graph) = random_graph(out, n, density)?;
+        let m = graph.m();
+
+        let (d_time, _, i2_time) = benchmark_algorithms(out, &graph, &id)?;
+
+        // Operation counts are deterministic, so average them once over the
+        // same sources `measure` uses
+        let sources = out.sources(n);
+        let mean_ops = |count: &dyn Fn(usize) -> OperationCounts| {
+            sources.iter().map(|&s| count(s).total() as f64).sum::<f64>() / sources.len().max(1) as f64
+        };
+        let d_count = mean_ops(&|s| dijkstra_with_counts(&graph, s).1);
+        let i2_count = mean_ops(&|s| improved_sssp_v2_with_counts(&graph, s).1);
+
+        d_times.push(Sample { n, m, value: d_time });
+        i2_times.push(Sample { n, m, value: i2_time });
+        d_ops.push(Sample { n, m, value: d_count });
+        i2_ops.push(Sample { n, m, value: i2_count });
+
+        out.line(format!("{:<10} {:<10} {:<15.3} {:<15.3} {:<15.0} {:<15.0}",
+                 n, m, d_time, i2_time, d_count, i2_count));
+    }
+
+    out.line(String::new());
+    out.line(format!("{:<22} {:<16} {:<16} {:<16} {:<16} {:<16}",
+             "Series", "Claimed", Model::MLogN, Model::MLogTwoThirdsN, Model::MPlusNLogN, "Best fit"));
+
+    let series = [
+        ("Dijkstra time", &d_times, Some(Model::MLogN)),
+        ("ImprovedV2 time", &i2_times, Some(Model::MLogTwoThirdsN)),
+        ("Dijkstra ops", &d_ops, None),
+        ("ImprovedV2 ops", &i2_ops, None),
+    ];
+    let mut warnings = Vec::new();
+
+    for (name, samples, claimed) in series {
+        let Some(ranking) = Ranking::of(samples) else {
+            out.line(format!("{:<22} not enough data to fit", name));
+            continue;
+        };
+        let r_squared = |model| ranking.get(model).map_or("-".to_string(), |fit| format!("R²={:.4}", fit.r_squared));
+
+        out.line(format!("{:<22} {:<16} {:<16} {:<16} {:<16} {:<16}",
+                 name, claimed.map_or("-", |model| model.name()),
+                 r_squared(Model::MLogN), r_squared(Model::MLogTwoThirdsN), r_squared(Model::MPlusNLogN),
+                 ranking.best().model));
+
+        if let Some(model) = claimed {
+            if !ranking.supports(model, FIT_MARGIN) {
+                warnings.push(format!("WARNING: {} does not support the claimed O({}); best fit is O({}) (R² {:.4} vs {:.4})",
+                                      name, model, ranking.best().model,
+                                      ranking.get(model).map_or(f64::NAN, |fit| fit.r_squared), ranking.best().r_squared));
+            }
+        }
+    }
+
+    for warning in warnings {
+        out.line(warning);
+    }
+    Ok(())
+}
+
+fn benchmark_algorithms(out: &mut Output, graph: &Graph, id: &str) -> Result<(f64, f64, f64), String> {
+    let d_time = measure(out, id, graph, "dijkstra", &[], |s| dijkstra(graph, s))?;
+    let i1_time = measure(out, id, graph, "improved", &[], |s| improved_sssp(graph, s))?;
+    let i2_time = measure(out, id, graph, "improved-v2", &[], |s| improved_sssp_v2(graph, s))?;
+
+    Ok((d_time, i1_time, i2_time))
+}
+
+fn parallel_scaling(out: &mut Output, sizes: &[usize], thread_counts: &[usize]) -> Result<(), String> {
+    out.line(format!("Random graphs with density {}", PARALLEL_DENSITY));
+    out.line(format!("{:<10} {:<10} {:<10} {:<20} {:<20} {:<15}",
+             "Nodes", "Edges", "Threads", "ParDeltaStep (ms)", "ParBellmanFord (ms)", "Dijkstra (ms)"));
+
+    for &n in sizes {
+        let (id, graph) = random_graph(out, n, PARALLEL_DENSITY)?;
+        let csr = CsrGraph::from_graph(&graph);
+        let delta = auto_delta(&graph);
+        let m = graph.m();
+
+        let d_time = measure(out, &id, &graph, "dijkstra", &[], |s| dijkstra(&graph, s))?;
+
+        for &threads in thread_counts {
+            let pool = thread_pool(threads);
+            let counters = [("threads", threads as u64)];
+
+            let ds_time = measure(out, &id, &graph, "parallel-delta-stepping", &counters,
+                                  |s| pool.install(|| parallel_delta_stepping(&csr, s, delta)))?;
+            let bf_time = measure(out, &id, &graph, "parallel-bellman-ford", &counters,
+                                  |s| pool.install(|| parallel_bellman_ford(&csr, s)).unwrap())?;
+
+            out.line(format!("{:<10} {:<10} {:<10} {:<20.3} {:<20.3} {:<15.3}",
+                     n, m, threads, ds_time, bf_time, d_time));
+        }
+    }
+    Ok(())
+}
+
+fn component_structure(out: &mut Output, n: usize, densities: &[f64]) -> Result<(), String> {
+    out.line(format!("{:<10} {:<10} {:<10} {:<12} {:<12} {:<12} {:<15} {:<15}",
+             "Nodes", "Edges", "SCCs", "Largest", "Singletons", "DAG Edges", "Dijkstra (ms)", "Condensed (ms)"));
+
+    for &density in densities {
+        let (id, graph) = random_graph(out, n, density)?;
+        let m = graph.m();
+
+        let condensation = Condensation::build(&graph);
+        let sizes = condensation.components.sizes();
+        let largest = sizes.iter().copied().max().unwrap_or(0);
+        let singletons = sizes.iter().filter(|&&s| s == 1).count();
+
+        let counters = [("sccs", condensation.components.count as u64), ("largest_scc", largest as u64)];
+
+        let d_time = measure(out, &id, &graph, "dijkstra", &counters, |s| dijkstra(&graph, s))?;
+        let c_time = measure(out, &id, &graph, "condensation", &counters,
+                             |s| condensation_sssp_with(&graph, &condensation.components, s))?;
+
+        out.line(format!("{:<10} {:<10} {:<10} {:<12} {:<12} {:<12} {:<15.3} {:<15.3}",
+                 n, m, condensation.components.count, largest, singletons,
+                 condensation.cross_edges(), d_time, c_time));
+    }
+    Ok(())
+}
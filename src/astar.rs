@@ -0,0 +1,209 @@
+use crate::graph::{Graph, WeightedGraph};
+use crate::dijkstra::dijkstra;
+use std::collections::BinaryHeap;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone)]
+struct Node {
+    id: usize,
+    dist: f64,
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.dist == other.dist
+    }
+}
+
+impl Eq for Node {}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.dist.partial_cmp(&self.dist).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Lower bound on the remaining distance used to guide A*
+pub trait Heuristic {
+    fn estimate(&self, v: usize, target: usize) -> f64;
+}
+
+/// Always 0, which turns A* back into Dijkstra
+pub struct ZeroHeuristic;
+
+impl Heuristic for ZeroHeuristic {
+    fn estimate(&self, _v: usize, _target: usize) -> f64 {
+        0.0
+    }
+}
+
+/// Straight-line distance between node coordinates, multiplied by `scale`
+pub struct EuclideanHeuristic<'a> {
+    coords: &'a [(f64, f64)],
+    scale: f64,
+}
+
+impl<'a> EuclideanHeuristic<'a> {
+    pub fn new(coords: &'a [(f64, f64)], scale: f64) -> Self {
+        EuclideanHeuristic { coords, scale }
+    }
+
+    /// Uses the graph's coordinates with the largest scale for which no edge
+    /// is shorter than its scaled straight-line length. This keeps the
+    /// heuristic consistent for any unit the weights are given in (e.g. DIMACS
+    /// travel times over longitude/latitude coordinates).
+    pub fn from_graph<G: WeightedGraph>(graph: &'a G) -> Option<Self> {
+        let coords = graph.coords()?;
+        let mut scale = f64::INFINITY;
+
+        for u in 0..graph.vertex_count() {
+            for (to, weight) in graph.out_neighbors(u) {
+                let d = euclidean(coords[u], coords[to]);
+                if d > 0.0 {
+                    scale = scale.min(weight / d);
+                }
+            }
+        }
+
+        if !scale.is_finite() {
+            scale = 1.0;
+        }
+
+        Some(EuclideanHeuristic { coords, scale: scale.max(0.0) })
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl Heuristic for EuclideanHeuristic<'_> {
+    fn estimate(&self, v: usize, target: usize) -> f64 {
+        euclidean(self.coords[v], self.coords[target]) * self.scale
+    }
+}
+
+fn euclidean(a: (f64, f64), b: (f64, f64)) -> f64 {
+    let dx = a.0 - b.0;
+    let dy = a.1 - b.1;
+    (dx * dx + dy * dy).sqrt()
+}
+
+/// A* point-to-point query. Same structure as `dijkstra`, but the heap is
+/// keyed by dist + heuristic and the search stops when `target` is settled.
+/// Returns `f64::INFINITY` when `target` is unreachable.
+pub fn astar<G: WeightedGraph, H: Heuristic>(graph: &G, source: usize, target: usize, heuristic: &H) -> f64 {
+    let n = graph.vertex_count();
+    let mut dist = vec![f64::INFINITY; n];
+    let mut heap = BinaryHeap::new();
+
+    dist[source] = 0.0;
+    heap.push(Node { id: source, dist: heuristic.estimate(source, target) });
+
+    while let Some(Node { id: u, dist: key }) = heap.pop() {
+        if u == target {
+            return dist[target];
+        }
+
+        if key > dist[u] + heuristic.estimate(u, target) {
+            continue;
+        }
+
+        for (to, weight) in graph.out_neighbors(u) {
+            let new_dist = dist[u] + weight;
+
+            if new_dist < dist[to] {
+                dist[to] = new_dist;
+                heap.push(Node { id: to, dist: new_dist + heuristic.estimate(to, target) });
+            }
+        }
+    }
+
+    dist[target]
+}
+
+/// Result of checking a heuristic against one target
+#[derive(Debug, Clone)]
+pub struct HeuristicCheck {
+    pub target: usize,
+    /// Edges (u, v) with h(u) > w(u, v) + h(v)
+    pub inconsistent_edges: Vec<(usize, usize)>,
+    /// Vertices whose estimate exceeds their true distance to the target
+    pub inadmissible_vertices: Vec<usize>,
+    pub max_overestimate: f64,
+}
+
+impl HeuristicCheck {
+    pub fn is_consistent(&self) -> bool {
+        self.inconsistent_edges.is_empty()
+    }
+
+    pub fn is_admissible(&self) -> bool {
+        self.inadmissible_vertices.is_empty()
+    }
+
+    /// Warning line when the heuristic is inadmissible (A* may return
+    /// non-shortest paths) or inconsistent (A* may re-expand vertices)
+    pub fn warning(&self) -> Option<String> {
+        if !self.is_admissible() {
+            Some(format!("WARNING: heuristic is inadmissible for target {}: overestimates at {} vertices by up to {:.2e}",
+                         self.target, self.inadmissible_vertices.len(), self.max_overestimate))
+        } else if !self.is_consistent() {
+            Some(format!("WARNING: heuristic is inconsistent for target {} on {} edges",
+                         self.target, self.inconsistent_edges.len()))
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks consistency on every edge and admissibility against exact
+/// distances to `target`. Prints nothing; see `warn_if_inadmissible`.
+pub fn check_heuristic<G: WeightedGraph, H: Heuristic>(graph: &G, heuristic: &H, target: usize) -> HeuristicCheck {
+    let eps = 1e-9;
+    let mut inconsistent_edges = Vec::new();
+
+    for u in 0..graph.vertex_count() {
+        let h_u = heuristic.estimate(u, target);
+        for (to, weight) in graph.out_neighbors(u) {
+            if h_u > weight + heuristic.estimate(to, target) + eps {
+                inconsistent_edges.push((u, to));
+            }
+        }
+    }
+
+    let to_target = dijkstra(&Graph::from_reverse(graph), target);
+    let mut inadmissible_vertices = Vec::new();
+    let mut max_overestimate = 0.0f64;
+
+    for (v, &d) in to_target.iter().enumerate() {
+        let over = heuristic.estimate(v, target) - d;
+        if d.is_finite() && over > eps {
+            inadmissible_vertices.push(v);
+            max_overestimate = max_overestimate.max(over);
+        }
+    }
+
+    HeuristicCheck {
+        target,
+        inconsistent_edges,
+        inadmissible_vertices,
+        max_overestimate,
+    }
+}
+
+/// `check_heuristic` that prints `HeuristicCheck::warning` to stderr, for
+/// callers about to trust A* results with this heuristic
+pub fn warn_if_inadmissible<G: WeightedGraph, H: Heuristic>(graph: &G, heuristic: &H, target: usize) -> HeuristicCheck {
+    let check = check_heuristic(graph, heuristic, target);
+    if let Some(warning) = check.warning() {
+        eprintln!("{}", warning);
+    }
+    check
+}
@@ -0,0 +1,99 @@
+use crate::report::Record;
+use crate::stats::Summary;
+
+/// Median solve time of one solver on one graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timing {
+    pub graph: String,
+    pub solver: String,
+    pub runs: usize,
+    pub median_ms: f64,
+}
+
+/// One timing per (graph, solver) pair, in order of first appearance
+pub fn summarize(records: &[Record]) -> Vec<Timing> {
+    let mut keys: Vec<(&str, &str)> = Vec::new();
+    for r in records {
+        if !keys.contains(&(r.graph.as_str(), r.solver.as_str())) {
+            keys.push((&r.graph, &r.solver));
+        }
+    }
+
+    keys.into_iter()
+        .filter_map(|(graph, solver)| {
+            let times: Vec<f64> = records
+                .iter()
+                .filter(|r| r.graph == graph && r.solver == solver)
+                .map(|r| r.time_ms)
+                .collect();
+            Summary::of(&times).map(|summary| Timing {
+                graph: graph.to_string(),
+                solver: solver.to_string(),
+                runs: summary.count,
+                median_ms: summary.median,
+            })
+        })
+        .collect()
+}
+
+/// Median time of a (graph, solver) pair present in both runs
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub graph: String,
+    pub solver: String,
+    pub baseline_ms: f64,
+    pub current_ms: f64,
+}
+
+impl Change {
+    /// Relative change of the median, e.g. 0.25 for 25% slower and -0.2
+    /// for 20% faster
+    pub fn slowdown(&self) -> f64 {
+        self.current_ms / self.baseline_ms - 1.0
+    }
+}
+
+/// A run compared against a stored baseline. Pairs are matched by graph id
+/// and solver name, so both runs must use the same graphs and seed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub changes: Vec<Change>,
+    /// (graph, solver) pairs in the baseline but not in this run
+    pub missing: Vec<(String, String)>,
+    /// (graph, solver) pairs in this run but not in the baseline
+    pub added: Vec<(String, String)>,
+}
+
+impl Comparison {
+    pub fn new(baseline: &[Record], current: &[Record]) -> Self {
+        let baseline = summarize(baseline);
+        let current = summarize(current);
+        let find = |timings: &[Timing], t: &Timing| timings.iter().position(|o| o.graph == t.graph && o.solver == t.solver);
+
+        let mut changes = Vec::new();
+        let mut added = Vec::new();
+        for t in &current {
+            match find(&baseline, t) {
+                Some(i) => changes.push(Change {
+                    graph: t.graph.clone(),
+                    solver: t.solver.clone(),
+                    baseline_ms: baseline[i].median_ms,
+                    current_ms: t.median_ms,
+                }),
+                None => added.push((t.graph.clone(), t.solver.clone())),
+            }
+        }
+        let missing = baseline
+            .iter()
+            .filter(|t| find(&current, t).is_none())
+            .map(|t| (t.graph.clone(), t.solver.clone()))
+            .collect();
+
+        Comparison { changes, missing, added }
+    }
+
+    /// Changes slower than the baseline by more than `threshold` (0.1 for 10%)
+    pub fn regressions(&self, threshold: f64) -> Vec<&Change> {
+        self.changes.iter().filter(|c| c.slowdown() > threshold).collect()
+    }
+}
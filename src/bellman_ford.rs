@@ -0,0 +1,139 @@
+use crate::graph::WeightedGraph;
+use std::collections::VecDeque;
+use std::fmt;
+
+const NONE: usize = usize::MAX;
+
+/// A cycle of negative total weight, as vertices in edge order:
+/// cycle[0] -> cycle[1] -> ... -> cycle[last] -> cycle[0]
+#[derive(Debug, Clone, PartialEq)]
+pub struct NegativeCycle {
+    pub cycle: Vec<usize>,
+}
+
+impl NegativeCycle {
+    /// Total weight of the cycle, using the lightest edge between consecutive vertices
+    pub fn weight<G: WeightedGraph>(&self, graph: &G) -> f64 {
+        let k = self.cycle.len();
+        (0..k)
+            .map(|i| {
+                let (u, v) = (self.cycle[i], self.cycle[(i + 1) % k]);
+                graph
+                    .out_neighbors(u)
+                    .filter(|&(to, _)| to == v)
+                    .map(|(_, weight)| weight)
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .sum()
+    }
+}
+
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "negative cycle through {} vertices: {:?}", self.cycle.len(), self.cycle)
+    }
+}
+
+impl std::error::Error for NegativeCycle {}
+
+/// Single-source shortest paths with arbitrary (including negative) weights.
+/// Returns the negative cycle instead if one is reachable from `source`.
+pub fn bellman_ford<G: WeightedGraph>(graph: &G, source: usize) -> Result<Vec<f64>, NegativeCycle> {
+    spfa(graph, &[source])
+}
+
+/// Searches the whole graph (not only what one source reaches) for a
+/// negative cycle
+pub fn find_negative_cycle<G: WeightedGraph>(graph: &G) -> Option<NegativeCycle> {
+    potentials(graph).err()
+}
+
+/// Johnson potentials: distances from a virtual source joined to every
+/// vertex by a 0-weight edge. For every edge u -> v, h[v] <= h[u] + w, so
+/// w + h[u] - h[v] >= 0.
+pub fn potentials<G: WeightedGraph>(graph: &G) -> Result<Vec<f64>, NegativeCycle> {
+    let all: Vec<usize> = (0..graph.vertex_count()).collect();
+    spfa(graph, &all)
+}
+
+/// Queue-based Bellman-Ford (SPFA) from every vertex in `sources` at
+/// distance 0.
+///
+/// Every n relaxations the parent pointers are checked for a cycle. Any
+/// such cycle is negative, and with a reachable negative cycle one must
+/// eventually appear, since tree paths alone cannot decrease forever.
+fn spfa<G: WeightedGraph>(graph: &G, sources: &[usize]) -> Result<Vec<f64>, NegativeCycle> {
+    let n = graph.vertex_count();
+    let mut dist = vec![f64::INFINITY; n];
+    let mut parent = vec![NONE; n];
+    let mut in_queue = vec![false; n];
+    let mut queue = VecDeque::new();
+    let mut relaxations = 0usize;
+
+    for &s in sources {
+        dist[s] = 0.0;
+        in_queue[s] = true;
+        queue.push_back(s);
+    }
+
+    while let Some(u) = queue.pop_front() {
+        in_queue[u] = false;
+
+        for (to, weight) in graph.out_neighbors(u) {
+            let new_dist = dist[u] + weight;
+
+            if new_dist < dist[to] {
+                dist[to] = new_dist;
+                parent[to] = u;
+                relaxations += 1;
+
+                if relaxations.is_multiple_of(n) {
+                    if let Some(cycle) = parent_cycle(&parent) {
+                        return Err(NegativeCycle { cycle });
+                    }
+                }
+
+                if !in_queue[to] {
+                    in_queue[to] = true;
+                    queue.push_back(to);
+                }
+            }
+        }
+    }
+
+    Ok(dist)
+}
+
+/// Finds a cycle in the parent-pointer graph, in edge order
+fn parent_cycle(parent: &[usize]) -> Option<Vec<usize>> {
+    const UNSEEN: u8 = 0;
+    const ON_WALK: u8 = 1;
+    const DONE: u8 = 2;
+
+    let mut state = vec![UNSEEN; parent.len()];
+    let mut walk = Vec::new();
+
+    for start in 0..parent.len() {
+        walk.clear();
+        let mut v = start;
+        while v != NONE && state[v] == UNSEEN {
+            state[v] = ON_WALK;
+            walk.push(v);
+            v = parent[v];
+        }
+
+        if v != NONE && state[v] == ON_WALK {
+            // The walk follows edges backwards, so reverse the cycle part
+            let at = walk.iter().position(|&x| x == v)?;
+            let mut cycle = walk[at..].to_vec();
+            cycle.reverse();
+            return Some(cycle);
+        }
+
+        for &x in &walk {
+            state[x] = DONE;
+        }
+    }
+
+    None
+}
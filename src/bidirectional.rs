@@ -0,0 +1,103 @@
+use crate::graph::WeightedGraph;
+use std::collections::BinaryHeap;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone)]
+struct Node {
+    id: usize,
+    dist: f64,
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.dist == other.dist
+    }
+}
+
+impl Eq for Node {}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.dist.partial_cmp(&self.dist).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Point-to-point query running Dijkstra from both ends.
+///
+/// `reverse` must hold the edges of `graph` reversed (`Graph::reverse` or
+/// `Graph::from_reverse`, or a backend that is its own reverse); it is taken
+/// as a parameter so it can be built once and shared across many queries.
+/// Returns `f64::INFINITY` when `target` is unreachable.
+pub fn bidirectional_dijkstra<G: WeightedGraph, R: WeightedGraph>(graph: &G, reverse: &R, source: usize, target: usize) -> f64 {
+    if source == target {
+        return 0.0;
+    }
+
+    let n = graph.vertex_count();
+    let mut dist_f = vec![f64::INFINITY; n];
+    let mut dist_b = vec![f64::INFINITY; n];
+    let mut heap_f = BinaryHeap::new();
+    let mut heap_b = BinaryHeap::new();
+
+    dist_f[source] = 0.0;
+    dist_b[target] = 0.0;
+    heap_f.push(Node { id: source, dist: 0.0 });
+    heap_b.push(Node { id: target, dist: 0.0 });
+
+    // Length of the best s-t path seen so far
+    let mut best = f64::INFINITY;
+
+    loop {
+        let top_f = heap_f.peek().map_or(f64::INFINITY, |node| node.dist);
+        let top_b = heap_b.peek().map_or(f64::INFINITY, |node| node.dist);
+
+        // No unsettled vertex can lie on a path shorter than top_f + top_b
+        if top_f + top_b >= best {
+            break;
+        }
+
+        if top_f <= top_b {
+            search_step(graph, &mut heap_f, &mut dist_f, &dist_b, &mut best);
+        } else {
+            search_step(reverse, &mut heap_b, &mut dist_b, &dist_f, &mut best);
+        }
+    }
+
+    best
+}
+
+fn search_step<G: WeightedGraph>(
+    graph: &G,
+    heap: &mut BinaryHeap<Node>,
+    dist: &mut [f64],
+    other_dist: &[f64],
+    best: &mut f64,
+) {
+    let Some(Node { id: u, dist: d }) = heap.pop() else {
+        return;
+    };
+    if d > dist[u] {
+        return;
+    }
+
+    for (to, weight) in graph.out_neighbors(u) {
+        let new_dist = dist[u] + weight;
+
+        if new_dist < dist[to] {
+            dist[to] = new_dist;
+            heap.push(Node { id: to, dist: new_dist });
+        }
+
+        // Meeting point: the other search has already reached `to`
+        let through = dist[to] + other_dist[to];
+        if through < *best {
+            *best = through;
+        }
+    }
+}
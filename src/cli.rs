@@ -0,0 +1,300 @@
+use crate::graph::Graph;
+use crate::dimacs::read_graph_with_coordinates;
+use crate::mmap_graph::MmapGraph;
+use crate::solver::Solver;
+use crate::report::{renderer, Format, Renderer};
+use rand::rngs::StdRng;
+use rand::seq::index::sample;
+use rand::SeedableRng;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Parsed command-line options: `--name value`, `--name=value`, bare
+/// `--flag` for names listed as flags, and positional arguments
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    values: Vec<(String, String)>,
+    flags: Vec<String>,
+    pub positional: Vec<String>,
+}
+
+impl Options {
+    /// `flags` lists the options that take no value
+    pub fn parse<I: IntoIterator<Item = String>>(args: I, flags: &[&str]) -> Result<Self, String> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let Some(name) = arg.strip_prefix("--") else {
+                options.positional.push(arg);
+                continue;
+            };
+
+            if let Some((name, value)) = name.split_once('=') {
+                options.values.push((name.to_string(), value.to_string()));
+            } else if flags.contains(&name) {
+                options.flags.push(name.to_string());
+            } else {
+                let value = args.next().ok_or_else(|| format!("--{} needs a value", name))?;
+                options.values.push((name.to_string(), value));
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Fails on any option not in `known` (flags included)
+    pub fn check_known(&self, known: &[&str]) -> Result<(), String> {
+        let names = self.values.iter().map(|(name, _)| name).chain(&self.flags);
+        for name in names {
+            if !known.contains(&name.as_str()) {
+                return Err(format!("unknown option --{}", name));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|f| f == name)
+    }
+
+    /// Last value given for `name`
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.iter().rev().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+    }
+
+    /// Every value of a repeatable option, in order
+    pub fn get_all(&self, name: &str) -> Vec<&str> {
+        self.values.iter().filter(|(n, _)| n == name).map(|(_, v)| v.as_str()).collect()
+    }
+
+    /// Like `get_all`, with comma-separated lists split
+    pub fn get_list(&self, name: &str) -> Vec<&str> {
+        self.get_all(name)
+            .into_iter()
+            .flat_map(|v| v.split(',').filter(|s| !s.is_empty()))
+            .collect()
+    }
+
+    /// `get_list` with every item parsed
+    pub fn parse_list<T: FromStr>(&self, name: &str) -> Result<Vec<T>, String> {
+        self.get_list(name)
+            .into_iter()
+            .map(|v| v.parse().map_err(|_| format!("invalid value for --{}: '{}'", name, v)))
+            .collect()
+    }
+
+    pub fn parse_or<T: FromStr>(&self, name: &str, default: T) -> Result<T, String> {
+        match self.get(name) {
+            Some(value) => value.parse().map_err(|_| format!("invalid value for --{}: '{}'", name, value)),
+            None => Ok(default),
+        }
+    }
+}
+
+/// Where a graph comes from: a seeded generator or DIMACS files.
+///
+/// Written as `random:n=1000,density=0.05[,max_weight=100]`,
+/// `geometric:n=1000,radius=0.05`, `grid:rows=100,cols=100[,max_weight=100]`,
+/// `scale-free:n=1000,attach=3[,max_weight=100]`, `file:path=g.gr[,co=g.co]`,
+/// or just a path to a `.gr` file. Paths ending in `.bin` are read as
+/// binary CSR files (see `mmap_graph`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraphSpec {
+    Random { n: usize, density: f64, max_weight: f64 },
+    Geometric { n: usize, radius: f64 },
+    Grid { rows: usize, cols: usize, max_weight: f64 },
+    ScaleFree { n: usize, attach: usize, max_weight: f64 },
+    File { path: PathBuf, coords: Option<PathBuf> },
+}
+
+impl GraphSpec {
+    /// Builds the graph; generated graphs are a function of the spec and `seed`
+    pub fn build(&self, seed: u64) -> Result<Graph, String> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        match self {
+            GraphSpec::Random { n, density, max_weight } => {
+                Ok(Graph::generate_random_with_rng(&mut rng, *n, *density, *max_weight))
+            }
+            GraphSpec::Geometric { n, radius } => Ok(Graph::generate_geometric_with_rng(&mut rng, *n, *radius)),
+            GraphSpec::Grid { rows, cols, max_weight } => {
+                Ok(Graph::generate_grid_with_rng(&mut rng, *rows, *cols, *max_weight))
+            }
+            GraphSpec::ScaleFree { n, attach, max_weight } => {
+                Ok(Graph::generate_scale_free_with_rng(&mut rng, *n, *attach, *max_weight))
+            }
+            GraphSpec::File { path, coords: None } if path.extension().is_some_and(|ext| ext == "bin") => {
+                MmapGraph::open(path)
+                    .map(|mapped| Graph::from_view(&mapped))
+                    .map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            GraphSpec::File { path, coords } => read_graph_with_coordinates(path, coords.as_ref())
+                .map_err(|e| format!("{}: {}", path.display(), e)),
+        }
+    }
+}
+
+impl FromStr for GraphSpec {
+    type Err = String;
+
+    fn from_str(spec: &str) -> Result<Self, String> {
+        let Some((family, params)) = spec.split_once(':') else {
+            return Ok(GraphSpec::File { path: PathBuf::from(spec), coords: None });
+        };
+
+        let mut pairs = Vec::new();
+        for pair in params.split(',').filter(|p| !p.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected key=value in graph spec, got '{}'", pair))?;
+            pairs.push((key, value));
+        }
+        let get = |key: &str| pairs.iter().find(|(k, _)| *k == key).map(|(_, v)| *v);
+        let known = |keys: &[&str]| match pairs.iter().find(|(k, _)| !keys.contains(k)) {
+            Some((k, _)) => Err(format!("unknown parameter '{}' for {} graphs", k, family)),
+            None => Ok(()),
+        };
+
+        match family {
+            "random" => {
+                known(&["n", "density", "max_weight"])?;
+                Ok(GraphSpec::Random {
+                    n: required(get("n"), "n")?,
+                    density: required(get("density"), "density")?,
+                    max_weight: optional(get("max_weight"), "max_weight", 100.0)?,
+                })
+            }
+            "geometric" => {
+                known(&["n", "radius"])?;
+                Ok(GraphSpec::Geometric {
+                    n: required(get("n"), "n")?,
+                    radius: required(get("radius"), "radius")?,
+                })
+            }
+            "grid" => {
+                known(&["rows", "cols", "max_weight"])?;
+                Ok(GraphSpec::Grid {
+                    rows: required(get("rows"), "rows")?,
+                    cols: required(get("cols"), "cols")?,
+                    max_weight: optional(get("max_weight"), "max_weight", 100.0)?,
+                })
+            }
+            "scale-free" => {
+                known(&["n", "attach", "max_weight"])?;
+                Ok(GraphSpec::ScaleFree {
+                    n: required(get("n"), "n")?,
+                    attach: required(get("attach"), "attach")?,
+                    max_weight: optional(get("max_weight"), "max_weight", 100.0)?,
+                })
+            }
+            "file" => {
+                known(&["path", "co"])?;
+                Ok(GraphSpec::File {
+                    path: PathBuf::from(get("path").ok_or("file graphs need path=...")?),
+                    coords: get("co").map(PathBuf::from),
+                })
+            }
+            _ => Err(format!("unknown graph family '{}'", family)),
+        }
+    }
+}
+
+fn required<T: FromStr>(value: Option<&str>, key: &str) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("graph spec is missing {}=...", key))?;
+    value.parse().map_err(|_| format!("invalid {} '{}' in graph spec", key, value))
+}
+
+fn optional<T: FromStr>(value: Option<&str>, key: &str, default: T) -> Result<T, String> {
+    match value {
+        Some(_) => required(value, key),
+        None => Ok(default),
+    }
+}
+
+/// Canonical spec string, usable as a graph id
+impl fmt::Display for GraphSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GraphSpec::Random { n, density, max_weight } => {
+                write!(f, "random:n={},density={},max_weight={}", n, density, max_weight)
+            }
+            GraphSpec::Geometric { n, radius } => write!(f, "geometric:n={},radius={}", n, radius),
+            GraphSpec::Grid { rows, cols, max_weight } => {
+                write!(f, "grid:rows={},cols={},max_weight={}", rows, cols, max_weight)
+            }
+            GraphSpec::ScaleFree { n, attach, max_weight } => {
+                write!(f, "scale-free:n={},attach={},max_weight={}", n, attach, max_weight)
+            }
+            GraphSpec::File { path, coords: None } => write!(f, "file:path={}", path.display()),
+            GraphSpec::File { path, coords: Some(co) } => {
+                write!(f, "file:path={},co={}", path.display(), co.display())
+            }
+        }
+    }
+}
+
+/// Largest absolute difference between two distance vectors; infinite if
+/// they disagree on which vertices are reachable
+pub fn max_difference(actual: &[f64], expected: &[f64]) -> f64 {
+    actual.iter().zip(expected).fold(0.0f64, |max, (&a, &e)| {
+        if a.is_finite() && e.is_finite() {
+            max.max((a - e).abs())
+        } else if a.is_finite() != e.is_finite() {
+            f64::INFINITY
+        } else {
+            max
+        }
+    })
+}
+
+/// Solvers named in `names`; `all` expands to the whole registry
+pub fn parse_solvers(names: &[&str]) -> Result<Vec<Solver>, String> {
+    let mut solvers = Vec::new();
+    for &name in names {
+        if name == "all" {
+            solvers.extend_from_slice(Solver::ALL);
+            continue;
+        }
+        let solver = Solver::from_name(name).ok_or_else(|| {
+            let known: Vec<&str> = Solver::ALL.iter().map(|s| s.name()).collect();
+            format!("unknown solver '{}' (known: {})", name, known.join(", "))
+        })?;
+        solvers.push(solver);
+    }
+    Ok(solvers)
+}
+
+/// Source vertices: the explicit `sources` if any, else `random` distinct
+/// vertices drawn with `seed`, else vertex 0
+pub fn select_sources(sources: &[usize], random: usize, n: usize, seed: u64) -> Result<Vec<usize>, String> {
+    if n == 0 {
+        return Err("graph has no vertices".to_string());
+    }
+    if let Some(&bad) = sources.iter().find(|&&s| s >= n) {
+        return Err(format!("source {} out of range for {} vertices", bad, n));
+    }
+    if !sources.is_empty() {
+        return Ok(sources.to_vec());
+    }
+    if random == 0 {
+        return Ok(vec![0]);
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    Ok(sample(&mut rng, n, random.min(n)).into_vec())
+}
+
+/// Record renderer for `--format` (default table), writing to `--output`
+/// or stdout
+pub fn open_renderer(options: &Options) -> Result<Box<dyn Renderer>, String> {
+    let name = options.get("format").unwrap_or("table");
+    let format = Format::from_name(name).ok_or_else(|| format!("unknown format '{}' (table, json, csv)", name))?;
+    let writer: Box<dyn Write> = match options.get("output") {
+        Some(path) => Box::new(BufWriter::new(File::create(path).map_err(|e| format!("{}: {}", path, e))?)),
+        None => Box::new(io::stdout()),
+    };
+    Ok(renderer(format, writer))
+}
@@ -0,0 +1,449 @@
+use crate::graph::WeightedGraph;
+use std::collections::{BinaryHeap, HashMap};
+use std::cmp::{Ordering, Reverse};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+const MAGIC: &[u8; 4] = b"CH01";
+const NO_VIA: u64 = u64::MAX;
+
+/// Vertices a witness search may settle before giving up and adding the shortcut
+const WITNESS_SETTLE_LIMIT: usize = 500;
+
+#[derive(Debug, Clone)]
+struct Node {
+    id: usize,
+    dist: f64,
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.dist == other.dist
+    }
+}
+
+impl Eq for Node {}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.dist.partial_cmp(&self.dist).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Edge of the hierarchy. `via` is the contracted middle vertex for shortcuts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChEdge {
+    pub to: usize,
+    pub weight: f64,
+    pub via: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Arc {
+    weight: f64,
+    via: Option<usize>,
+}
+
+/// Contraction hierarchy over any `WeightedGraph`.
+///
+/// `up[u]` holds edges u -> v with rank[v] > rank[u]. `down[v]` holds edges
+/// u -> v with rank[u] > rank[v], stored at v with `to = u` so the backward
+/// search from the target also only moves upward.
+#[derive(Debug, Clone)]
+pub struct ContractionHierarchy {
+    pub n: usize,
+    pub rank: Vec<usize>,
+    pub up: Vec<Vec<ChEdge>>,
+    pub down: Vec<Vec<ChEdge>>,
+}
+
+impl ContractionHierarchy {
+    /// Contracts every vertex, ordered lazily by edge difference
+    /// (shortcuts added minus edges removed) plus contracted neighbours
+    pub fn build<G: WeightedGraph>(graph: &G) -> Self {
+        let n = graph.vertex_count();
+        let mut contractor = Contractor::new(graph);
+        let mut hierarchy = ContractionHierarchy {
+            n,
+            rank: vec![0; n],
+            up: vec![Vec::new(); n],
+            down: vec![Vec::new(); n],
+        };
+
+        let mut queue: BinaryHeap<Reverse<(i64, usize)>> =
+            (0..n).map(|v| Reverse((contractor.priority(v), v))).collect();
+        let mut next_rank = 0;
+
+        while let Some(Reverse((_, v))) = queue.pop() {
+            if contractor.contracted[v] {
+                continue;
+            }
+
+            // Lazy update: re-evaluate and postpone if no longer the minimum
+            let priority = contractor.priority(v);
+            if let Some(Reverse((next, _))) = queue.peek() {
+                if priority > *next {
+                    queue.push(Reverse((priority, v)));
+                    continue;
+                }
+            }
+
+            for (&w, arc) in &contractor.out[v] {
+                hierarchy.up[v].push(ChEdge { to: w, weight: arc.weight, via: arc.via });
+            }
+            for (&u, arc) in &contractor.inc[v] {
+                hierarchy.down[v].push(ChEdge { to: u, weight: arc.weight, via: arc.via });
+            }
+            hierarchy.rank[v] = next_rank;
+            next_rank += 1;
+
+            contractor.contract(v);
+        }
+
+        hierarchy
+    }
+
+    /// Number of edges in the hierarchy that are shortcuts
+    pub fn shortcuts(&self) -> usize {
+        self.up.iter().chain(self.down.iter()).flatten().filter(|e| e.via.is_some()).count()
+    }
+
+    /// Shortest distance from `source` to `target`, `f64::INFINITY` if unreachable
+    pub fn query(&self, source: usize, target: usize) -> f64 {
+        self.search(source, target).best
+    }
+
+    /// Shortest distance and the path in the original graph
+    pub fn query_path(&self, source: usize, target: usize) -> Option<(f64, Vec<usize>)> {
+        let search = self.search(source, target);
+        let meeting = search.meeting?;
+
+        // Hierarchy edges from the source up to the meeting vertex
+        let mut forward = Vec::new();
+        let mut v = meeting;
+        while let Some((prev, via)) = search.parent_f[v] {
+            forward.push((prev, v, via));
+            v = prev;
+        }
+        forward.reverse();
+
+        let mut path = vec![source];
+        for (a, b, via) in forward {
+            self.unpack(a, b, via, &mut path);
+        }
+
+        // And from the meeting vertex down to the target
+        let mut v = meeting;
+        while let Some((next, via)) = search.parent_b[v] {
+            self.unpack(v, next, via, &mut path);
+            v = next;
+        }
+
+        Some((search.best, path))
+    }
+
+    fn search(&self, source: usize, target: usize) -> Search {
+        let n = self.n;
+        let mut dist_f = vec![f64::INFINITY; n];
+        let mut dist_b = vec![f64::INFINITY; n];
+        let mut parent_f = vec![None; n];
+        let mut parent_b = vec![None; n];
+        let mut heap_f = BinaryHeap::new();
+        let mut heap_b = BinaryHeap::new();
+
+        dist_f[source] = 0.0;
+        dist_b[target] = 0.0;
+        heap_f.push(Node { id: source, dist: 0.0 });
+        heap_b.push(Node { id: target, dist: 0.0 });
+
+        let mut best = f64::INFINITY;
+        let mut meeting = None;
+
+        if source == target {
+            return Search { best: 0.0, meeting: Some(source), parent_f, parent_b };
+        }
+
+        loop {
+            let top_f = heap_f.peek().map_or(f64::INFINITY, |node| node.dist);
+            let top_b = heap_b.peek().map_or(f64::INFINITY, |node| node.dist);
+
+            // Both upward searches must run until their minimum exceeds the
+            // best meeting distance; the first meeting is not always optimal
+            if top_f >= best && top_b >= best {
+                break;
+            }
+
+            let (heap, dist, parent, other, edges) = if top_f <= top_b {
+                (&mut heap_f, &mut dist_f, &mut parent_f, &dist_b, &self.up)
+            } else {
+                (&mut heap_b, &mut dist_b, &mut parent_b, &dist_f, &self.down)
+            };
+
+            let Some(Node { id: u, dist: d }) = heap.pop() else {
+                break;
+            };
+            if d > dist[u] {
+                continue;
+            }
+
+            if dist[u] + other[u] < best {
+                best = dist[u] + other[u];
+                meeting = Some(u);
+            }
+
+            for edge in &edges[u] {
+                let new_dist = dist[u] + edge.weight;
+                if new_dist < dist[edge.to] {
+                    dist[edge.to] = new_dist;
+                    parent[edge.to] = Some((u, edge.via));
+                    heap.push(Node { id: edge.to, dist: new_dist });
+                }
+            }
+        }
+
+        Search { best, meeting, parent_f, parent_b }
+    }
+
+    /// Appends the original vertices of edge a -> b (excluding a) to `path`
+    fn unpack(&self, a: usize, b: usize, via: Option<usize>, path: &mut Vec<usize>) {
+        match via {
+            None => path.push(b),
+            Some(m) => {
+                self.unpack(a, m, self.arc_via(a, m), path);
+                self.unpack(m, b, self.arc_via(m, b), path);
+            }
+        }
+    }
+
+    /// Middle vertex of the hierarchy edge a -> b
+    fn arc_via(&self, a: usize, b: usize) -> Option<usize> {
+        let edge = if self.rank[a] < self.rank[b] {
+            self.up[a].iter().find(|e| e.to == b)
+        } else {
+            self.down[b].iter().find(|e| e.to == a)
+        };
+        edge.and_then(|e| e.via)
+    }
+
+    /// Writes the hierarchy in a little-endian binary format
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        out.write_all(MAGIC)?;
+        out.write_all(&(self.n as u64).to_le_bytes())?;
+        for &r in &self.rank {
+            out.write_all(&(r as u64).to_le_bytes())?;
+        }
+        for adjacency in self.up.iter().chain(self.down.iter()) {
+            out.write_all(&(adjacency.len() as u64).to_le_bytes())?;
+            for edge in adjacency {
+                out.write_all(&(edge.to as u64).to_le_bytes())?;
+                out.write_all(&edge.weight.to_le_bytes())?;
+                out.write_all(&edge.via.map_or(NO_VIA, |v| v as u64).to_le_bytes())?;
+            }
+        }
+        out.flush()
+    }
+
+    /// Reads a hierarchy written by `save`. Every count in the file is
+    /// checked against the bytes left before it is allocated, and every
+    /// rank, target and middle vertex must be below n.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let len = file.metadata()?.len();
+        let mut input = BufReader::new(file);
+        let mut magic = [0u8; 4];
+        input.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid("not a contraction hierarchy".to_string()));
+        }
+
+        let n = read_u64(&mut input)?;
+        // Bytes after the header, less a rank and two list lengths per vertex
+        let mut remaining = len
+            .saturating_sub(MAGIC.len() as u64 + 8)
+            .checked_sub(n.saturating_mul(24))
+            .ok_or_else(|| invalid(format!("file has {} bytes, too short for n={}", len, n)))?;
+        let n = n as usize;
+
+        let vertex = |v: u64, what: &str| {
+            if v < n as u64 {
+                Ok(v as usize)
+            } else {
+                Err(invalid(format!("{} {} is not a vertex of {}", what, v, n)))
+            }
+        };
+
+        let rank = (0..n).map(|_| vertex(read_u64(&mut input)?, "rank")).collect::<io::Result<Vec<_>>>()?;
+        let mut adjacency = Vec::with_capacity(2 * n);
+        for _ in 0..2 * n {
+            let len = read_u64(&mut input)?;
+            remaining = remaining
+                .checked_sub(len.saturating_mul(24))
+                .ok_or_else(|| invalid(format!("edge list of {} entries runs past the end of the file", len)))?;
+            let mut edges = Vec::with_capacity(len as usize);
+            for _ in 0..len {
+                let to = vertex(read_u64(&mut input)?, "edge target")?;
+                let weight = f64::from_bits(read_u64(&mut input)?);
+                let via = match read_u64(&mut input)? {
+                    NO_VIA => None,
+                    via => Some(vertex(via, "shortcut middle")?),
+                };
+                edges.push(ChEdge { to, weight, via });
+            }
+            adjacency.push(edges);
+        }
+        if remaining != 0 {
+            return Err(invalid(format!("{} trailing bytes", remaining)));
+        }
+        let down = adjacency.split_off(n);
+
+        Ok(ContractionHierarchy { n, rank, up: adjacency, down })
+    }
+}
+
+struct Search {
+    best: f64,
+    meeting: Option<usize>,
+    parent_f: Vec<Option<(usize, Option<usize>)>>,
+    parent_b: Vec<Option<(usize, Option<usize>)>>,
+}
+
+/// Remaining (uncontracted) graph during preprocessing
+struct Contractor {
+    out: Vec<HashMap<usize, Arc>>,
+    inc: Vec<HashMap<usize, Arc>>,
+    contracted: Vec<bool>,
+    contracted_neighbors: Vec<i64>,
+}
+
+impl Contractor {
+    fn new<G: WeightedGraph>(graph: &G) -> Self {
+        let n = graph.vertex_count();
+        let mut contractor = Contractor {
+            out: vec![HashMap::new(); n],
+            inc: vec![HashMap::new(); n],
+            contracted: vec![false; n],
+            contracted_neighbors: vec![0; n],
+        };
+        for u in 0..n {
+            for (v, weight) in graph.out_neighbors(u) {
+                if u != v {
+                    contractor.add_arc(u, v, weight, None);
+                }
+            }
+        }
+        contractor
+    }
+
+    /// Inserts u -> v, keeping only the lightest of parallel arcs
+    fn add_arc(&mut self, u: usize, v: usize, weight: f64, via: Option<usize>) {
+        let arc = Arc { weight, via };
+        match self.out[u].get(&v) {
+            Some(existing) if existing.weight <= weight => {}
+            _ => {
+                self.out[u].insert(v, arc);
+                self.inc[v].insert(u, arc);
+            }
+        }
+    }
+
+    fn priority(&self, v: usize) -> i64 {
+        let removed = (self.out[v].len() + self.inc[v].len()) as i64;
+        let added = self.shortcuts(v).len() as i64;
+        added - removed + self.contracted_neighbors[v]
+    }
+
+    /// Shortcuts u -> w needed when v is removed, i.e. where no witness path
+    /// avoiding v is as short as u -> v -> w
+    fn shortcuts(&self, v: usize) -> Vec<(usize, usize, f64)> {
+        let mut shortcuts = Vec::new();
+        let max_out = self.out[v].values().map(|arc| arc.weight).fold(0.0, f64::max);
+
+        for (&u, in_arc) in &self.inc[v] {
+            let witness = self.witness_search(u, v, in_arc.weight + max_out);
+            for (&w, out_arc) in &self.out[v] {
+                if w == u {
+                    continue;
+                }
+                let through_v = in_arc.weight + out_arc.weight;
+                if witness.get(&w).is_none_or(|&d| d > through_v) {
+                    shortcuts.push((u, w, through_v));
+                }
+            }
+        }
+
+        shortcuts
+    }
+
+    /// Dijkstra from `source` in the remaining graph without `avoid`, stopped
+    /// at `max_dist` or after settling `WITNESS_SETTLE_LIMIT` vertices
+    fn witness_search(&self, source: usize, avoid: usize, max_dist: f64) -> HashMap<usize, f64> {
+        let mut dist = HashMap::new();
+        let mut heap = BinaryHeap::new();
+        let mut settled = 0;
+
+        dist.insert(source, 0.0);
+        heap.push(Node { id: source, dist: 0.0 });
+
+        while let Some(Node { id: u, dist: d }) = heap.pop() {
+            if d > dist[&u] {
+                continue;
+            }
+            if d > max_dist || settled >= WITNESS_SETTLE_LIMIT {
+                break;
+            }
+            settled += 1;
+
+            for (&x, arc) in &self.out[u] {
+                if x == avoid {
+                    continue;
+                }
+                let new_dist = d + arc.weight;
+                if dist.get(&x).is_none_or(|&old| new_dist < old) {
+                    dist.insert(x, new_dist);
+                    heap.push(Node { id: x, dist: new_dist });
+                }
+            }
+        }
+
+        dist
+    }
+
+    fn contract(&mut self, v: usize) {
+        for (u, w, weight) in self.shortcuts(v) {
+            self.add_arc(u, w, weight, Some(v));
+        }
+
+        let out: Vec<usize> = self.out[v].keys().copied().collect();
+        let inc: Vec<usize> = self.inc[v].keys().copied().collect();
+        for &w in &out {
+            self.inc[w].remove(&v);
+            self.contracted_neighbors[w] += 1;
+        }
+        for &u in &inc {
+            self.out[u].remove(&v);
+            self.contracted_neighbors[u] += 1;
+        }
+
+        self.out[v].clear();
+        self.inc[v].clear();
+        self.contracted[v] = true;
+    }
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn read_u64<R: Read>(input: &mut R) -> io::Result<u64> {
+    let mut buf = [0u8; 8];
+    input.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
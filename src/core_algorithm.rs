@@ -1,7 +1,5 @@
-use crate::graph::Graph;
-
-#[cfg(test)]
-use shortest_path_validation::graph::Graph as TestGraph;
+use crate::graph::{Graph, WeightedGraph};
+use crate::workspace::{EpochSet, EpochVec};
 use std::collections::{BinaryHeap, VecDeque, HashSet};
 use std::cmp::Ordering;
 
@@ -21,17 +19,61 @@ impl Eq for Node {}
 
 impl PartialOrd for Node {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.dist.partial_cmp(&self.dist)
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Node {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+        other.dist.partial_cmp(&self.dist).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Key parameters from the paper, (k, t)
+fn parameters(n: usize) -> (usize, usize) {
+    let log_n = (n as f64).ln().max(1.0);
+    let k = (log_n.powf(1.0 / 3.0)).ceil() as usize;
+    let t = (log_n.powf(2.0 / 3.0)).ceil() as usize;
+    (k.max(1), t.max(1))
+}
+
+/// The core algorithm on a borrowed graph. Holds only the graph and the
+/// parameters, so one solver answers any number of sources without copying
+/// the graph; pass a `CoreWorkspace` to reuse buffers between them.
+#[derive(Debug)]
+pub struct CoreSolver<'g, G: WeightedGraph = Graph> {
+    pub graph: &'g G,
+    pub k: usize,  // ⌊log^(1/3) n⌋
+    pub t: usize,  // ⌊log^(2/3) n⌋
+}
+
+// Manual impls: derived ones would require `G: Clone`
+impl<G: WeightedGraph> Clone for CoreSolver<'_, G> {
+    fn clone(&self) -> Self {
+        *self
     }
 }
 
-/// Core implementation following the paper's algorithm
+impl<G: WeightedGraph> Copy for CoreSolver<'_, G> {}
+
+impl<'g, G: WeightedGraph> CoreSolver<'g, G> {
+    pub fn new(graph: &'g G) -> Self {
+        let (k, t) = parameters(graph.vertex_count());
+        CoreSolver { graph, k, t }
+    }
+    
+    /// Distances from `source`, using a fresh workspace
+    pub fn sssp(&self, source: usize) -> Vec<f64> {
+        let mut workspace = CoreWorkspace::new(self.graph.vertex_count());
+        workspace.run(self, source);
+        workspace.distances()
+    }
+}
+
+/// Core implementation following the paper's algorithm, owning its graph.
+/// A thin wrapper over `CoreSolver`: `sssp` starts from fresh distances,
+/// and the step methods (`bmssp`, `find_pivots`, `base_case`) run the same
+/// `CoreWorkspace` code on `dist` for inspection.
 pub struct CoreAlgorithm {
     pub graph: Graph,
     pub dist: Vec<f64>,
@@ -42,164 +84,264 @@ pub struct CoreAlgorithm {
 impl CoreAlgorithm {
     pub fn new(graph: Graph) -> Self {
         let n = graph.n;
-        let log_n = (n as f64).ln().max(1.0);
-        
-        // Key parameters from the paper
-        let k = (log_n.powf(1.0 / 3.0)).ceil() as usize;
-        let t = (log_n.powf(2.0 / 3.0)).ceil() as usize;
+        let (k, t) = parameters(n);
         
         CoreAlgorithm {
             graph,
             dist: vec![f64::INFINITY; n],
-            k: k.max(1),
-            t: t.max(1),
+            k,
+            t,
         }
     }
     
-    /// Main SSSP algorithm
-    pub fn sssp(mut self, source: usize) -> Vec<f64> {
-        let n = self.graph.n;
+    /// Borrowing solver over this graph
+    pub fn solver(&self) -> CoreSolver<'_> {
+        CoreSolver { graph: &self.graph, k: self.k, t: self.t }
+    }
+    
+    /// Main SSSP algorithm. Starts from fresh distances, not `dist`.
+    pub fn sssp(self, source: usize) -> Vec<f64> {
+        self.solver().sssp(source)
+    }
+    
+    /// Bounded Multi-Source Shortest Path (recursive), continuing from
+    /// `dist`. Sources are taken in increasing id order.
+    pub fn bmssp(&mut self, level: usize, bound: f64, sources: HashSet<usize>) {
+        self.step(|workspace, solver| {
+            let sources = workspace.sorted_buffer(&sources);
+            workspace.bmssp(solver, level, bound, sources);
+        });
+    }
+    
+    /// FindPivots: Reduces source set to important vertices
+    pub fn find_pivots(&mut self, bound: f64, sources: &HashSet<usize>) -> (HashSet<usize>, Vec<usize>) {
+        self.step(|workspace, solver| {
+            let sources = workspace.sorted_buffer(sources);
+            let (pivots, reachable) = workspace.find_pivots(solver, bound, &sources);
+            (pivots.into_iter().collect(), reachable)
+        })
+    }
+    
+    /// BaseCase: Dijkstra-like exploration for small sets
+    pub fn base_case(&mut self, source: usize, bound: f64) {
+        self.step(|workspace, solver| workspace.base_case(solver, source, bound));
+    }
+    
+    /// Runs one step of the `CoreWorkspace` search on `dist`: loads it into
+    /// a workspace and copies the result back, O(n) on top of the step
+    fn step<R>(&mut self, step: impl FnOnce(&mut CoreWorkspace, &CoreSolver) -> R) -> R {
+        let solver = CoreSolver { graph: &self.graph, k: self.k, t: self.t };
+        let mut workspace = CoreWorkspace::new(self.graph.n);
+        workspace.load(&self.dist);
+        let result = step(&mut workspace, &solver);
+        workspace.dist.write_to(&mut self.dist);
+        result
+    }
+    
+    /// Get algorithm parameters for testing
+    pub fn get_params(&self) -> (usize, usize) {
+        (self.k, self.t)
+    }
+}
+
+/// Buffers for `CoreSolver` queries, and the one implementation of the
+/// recursion: distances and the visited/processed marks reset by epoch,
+/// and frontier vectors are recycled through a pool, so repeated queries
+/// after the first few do not allocate.
+pub struct CoreWorkspace {
+    dist: EpochVec<f64>,
+    /// Vertex count of the last graph searched
+    n: usize,
+    visited: EpochSet,
+    processed: EpochSet,
+    in_sources: EpochSet,
+    heap: BinaryHeap<Node>,
+    queue: VecDeque<usize>,
+    pool: Vec<Vec<usize>>,
+}
+
+impl CoreWorkspace {
+    pub fn new(n: usize) -> Self {
+        CoreWorkspace {
+            dist: EpochVec::new(n, f64::INFINITY),
+            n,
+            visited: EpochSet::new(n),
+            processed: EpochSet::new(n),
+            in_sources: EpochSet::new(n),
+            heap: BinaryHeap::new(),
+            queue: VecDeque::new(),
+            pool: Vec::new(),
+        }
+    }
+    
+    /// Runs `algo`'s search from `source`, leaving the result in the
+    /// workspace
+    pub fn run<G: WeightedGraph>(&mut self, algo: &CoreSolver<G>, source: usize) {
+        let n = algo.graph.vertex_count();
+        assert!(n <= self.dist.len(), "workspace for {} vertices, graph has {}", self.dist.len(), n);
+        self.n = n;
         let log_n = (n as f64).ln();
-        let l = ((log_n / self.t as f64).ceil() as usize).max(1);
-        
-        self.dist[source] = 0.0;
+        let l = ((log_n / algo.t as f64).ceil() as usize).max(1);
         
-        let mut frontier = HashSet::new();
-        frontier.insert(source);
+        self.dist.reset();
+        self.dist.set(source, 0.0);
         
-        self.bmssp(l, f64::INFINITY, frontier);
-        self.dist
+        let mut frontier = self.buffer();
+        frontier.push(source);
+        self.bmssp(algo, l, f64::INFINITY, frontier);
     }
     
-    /// Bounded Multi-Source Shortest Path (recursive)
-    fn bmssp(&mut self, level: usize, bound: f64, sources: HashSet<usize>) {
-        if sources.is_empty() {
-            return;
+    /// Distance from the last source, infinite if unreachable
+    pub fn distance(&self, v: usize) -> f64 {
+        self.dist.get(v)
+    }
+    
+    /// All distances from the last query, as `CoreSolver::sssp` returns them:
+    /// one per vertex of that graph, however large the workspace
+    pub fn distances(&self) -> Vec<f64> {
+        (0..self.n).map(|v| self.dist.get(v)).collect()
+    }
+    
+    /// Starts from `dist` instead of an empty distance array
+    fn load(&mut self, dist: &[f64]) {
+        self.n = dist.len();
+        self.dist.reset();
+        for (v, &d) in dist.iter().enumerate().filter(|(_, d)| d.is_finite()) {
+            self.dist.set(v, d);
         }
-        
-        // Base case
-        if level == 0 || sources.len() == 1 {
+    }
+    
+    fn sorted_buffer(&mut self, vertices: &HashSet<usize>) -> Vec<usize> {
+        let mut buffer = self.buffer();
+        buffer.extend(vertices);
+        buffer.sort_unstable();
+        buffer
+    }
+    
+    fn buffer(&mut self) -> Vec<usize> {
+        self.pool.pop().unwrap_or_default()
+    }
+    
+    fn recycle(&mut self, mut buffer: Vec<usize>) {
+        buffer.clear();
+        self.pool.push(buffer);
+    }
+    
+    /// Bounded multi-source search: base case on single sources or at level
+    /// 0, otherwise pivots first at half the bound, then the rest of the
+    /// reached vertices. Takes ownership of `sources` and recycles it.
+    fn bmssp<G: WeightedGraph>(&mut self, algo: &CoreSolver<G>, level: usize, bound: f64, sources: Vec<usize>) {
+        if level == 0 || sources.len() <= 1 {
             for &s in &sources {
-                self.base_case(s, bound);
+                self.base_case(algo, s, bound);
             }
+            self.recycle(sources);
             return;
         }
         
-        // Find pivots and reduce the frontier
-        let (pivots, reachable) = self.find_pivots(bound, &sources);
+        let (mut pivots, reachable) = self.find_pivots(algo, bound, &sources);
+        self.recycle(sources);
         
         if pivots.is_empty() {
+            self.recycle(pivots);
+            self.recycle(reachable);
             return;
         }
         
-        // Recursive calls with reduced parameters
-        let new_level = level - 1;
-        let new_bound = bound / 2.0;
-        
-        // Process pivots first
-        self.bmssp(new_level, new_bound, pivots.clone());
+        // Sorted for the membership test below, which runs after the
+        // recursion has reused the marks
+        pivots.sort_unstable();
+        let mut first = self.buffer();
+        first.extend_from_slice(&pivots);
+        self.bmssp(algo, level - 1, bound / 2.0, first);
         
-        // Process remaining vertices
-        let mut remaining = HashSet::new();
-        for v in reachable {
-            if !pivots.contains(&v) && self.dist[v] < bound {
-                remaining.insert(v);
+        let mut remaining = self.buffer();
+        for &v in &reachable {
+            if pivots.binary_search(&v).is_err() && self.dist.get(v) < bound {
+                remaining.push(v);
             }
         }
+        self.recycle(pivots);
+        self.recycle(reachable);
         
-        if !remaining.is_empty() {
-            self.bmssp(new_level, bound, remaining);
-        }
+        self.bmssp(algo, level - 1, bound, remaining);
     }
     
-    /// FindPivots: Reduces source set to important vertices
-    pub fn find_pivots(&mut self, bound: f64, sources: &HashSet<usize>) -> (HashSet<usize>, Vec<usize>) {
-        let mut pivots = HashSet::new();
-        let mut reachable = Vec::new();
-        let mut queue = VecDeque::new();
-        let mut visited = HashSet::new();
+    /// k rounds of bounded relaxation from `sources`. Sources that reach at
+    /// least k vertices become pivots, at most |sources| / k of them, kept
+    /// in discovery order.
+    fn find_pivots<G: WeightedGraph>(&mut self, algo: &CoreSolver<G>, bound: f64, sources: &[usize]) -> (Vec<usize>, Vec<usize>) {
+        let mut pivots = self.buffer();
+        let mut reachable = self.buffer();
+        self.queue.clear();
+        self.visited.clear();
+        self.in_sources.clear();
         
-        // Initialize with source vertices
         for &s in sources {
-            if self.dist[s] < bound {
-                queue.push_back(s);
-                visited.insert(s);
+            self.in_sources.insert(s);
+            if self.dist.get(s) < bound && self.visited.insert(s) {
+                self.queue.push_back(s);
             }
         }
         
-        // Perform k rounds of relaxation
-        for _round in 0..self.k {
-            let round_size = queue.len();
+        for _round in 0..algo.k {
+            let round_size = self.queue.len();
             if round_size == 0 {
                 break;
             }
             
             for _ in 0..round_size {
-                if let Some(u) = queue.pop_front() {
-                    // Count vertices reached from u
-                    let mut reached_count = 0;
+                let Some(u) = self.queue.pop_front() else { break };
+                let mut reached_count = 0;
+                
+                for (to, weight) in algo.graph.out_neighbors(u) {
+                    let new_dist = self.dist.get(u) + weight;
                     
-                    for edge in &self.graph.edges[u] {
-                        let new_dist = self.dist[u] + edge.weight;
+                    if new_dist < bound && new_dist < self.dist.get(to) {
+                        self.dist.set(to, new_dist);
+                        reached_count += 1;
                         
-                        if new_dist < bound && new_dist < self.dist[edge.to] {
-                            self.dist[edge.to] = new_dist;
-                            reached_count += 1;
-                            
-                            if !visited.contains(&edge.to) {
-                                visited.insert(edge.to);
-                                queue.push_back(edge.to);
-                                reachable.push(edge.to);
-                            }
+                        if self.visited.insert(to) {
+                            self.queue.push_back(to);
+                            reachable.push(to);
                         }
                     }
-                    
-                    // Add to pivots if it reaches enough vertices
-                    if sources.contains(&u) && reached_count >= self.k {
-                        pivots.insert(u);
-                    }
+                }
+                
+                if self.in_sources.contains(u) && reached_count >= algo.k {
+                    pivots.push(u);
                 }
             }
         }
         
-        // Ensure we don't have too many pivots
-        let max_pivots = (sources.len() / self.k).max(1);
-        let pivots: HashSet<usize> = pivots.into_iter().take(max_pivots).collect();
-        
+        pivots.truncate((sources.len() / algo.k).max(1));
         (pivots, reachable)
     }
     
-    /// BaseCase: Dijkstra-like exploration for small sets
-    pub fn base_case(&mut self, source: usize, bound: f64) {
-        let mut heap = BinaryHeap::new();
-        let mut processed = HashSet::new();
+    /// Dijkstra from `source` over distances below `bound`
+    fn base_case<G: WeightedGraph>(&mut self, algo: &CoreSolver<G>, source: usize, bound: f64) {
+        self.heap.clear();
+        self.processed.clear();
         
-        if self.dist[source] < bound {
-            heap.push(Node { id: source, dist: self.dist[source] });
+        if self.dist.get(source) < bound {
+            self.heap.push(Node { id: source, dist: self.dist.get(source) });
         }
         
-        // For base case, explore all reachable vertices within bound
-        while let Some(Node { id: u, dist: d }) = heap.pop() {
-            if processed.contains(&u) || d >= bound {
+        while let Some(Node { id: u, dist: d }) = self.heap.pop() {
+            if d >= bound || !self.processed.insert(u) {
                 continue;
             }
-            processed.insert(u);
             
-            for edge in &self.graph.edges[u] {
-                let new_dist = self.dist[u] + edge.weight;
+            for (to, weight) in algo.graph.out_neighbors(u) {
+                let new_dist = self.dist.get(u) + weight;
                 
-                if new_dist < bound && new_dist < self.dist[edge.to] {
-                    self.dist[edge.to] = new_dist;
-                    heap.push(Node { id: edge.to, dist: new_dist });
+                if new_dist < bound && new_dist < self.dist.get(to) {
+                    self.dist.set(to, new_dist);
+                    self.heap.push(Node { id: to, dist: new_dist });
                 }
             }
         }
     }
-    
-    /// Get algorithm parameters for testing
-    pub fn get_params(&self) -> (usize, usize) {
-        (self.k, self.t)
-    }
 }
 
 /// Partial sorting data structure from the paper
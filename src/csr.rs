@@ -0,0 +1,74 @@
+use crate::graph::{Graph, WeightedGraph};
+use std::iter::{Copied, Zip};
+use std::slice::Iter;
+
+/// Compressed sparse row layout: the out-edges of `u` are
+/// `targets[offsets[u]..offsets[u + 1]]` with matching `weights`.
+///
+/// Three flat arrays instead of one `Vec` per vertex, so threads scanning
+/// different vertices read disjoint contiguous ranges.
+#[derive(Debug, Clone)]
+pub struct CsrGraph {
+    pub n: usize,
+    pub offsets: Vec<usize>,
+    pub targets: Vec<usize>,
+    pub weights: Vec<f64>,
+}
+
+impl CsrGraph {
+    pub fn from_graph(graph: &Graph) -> Self {
+        Self::from_view(graph)
+    }
+
+    /// CSR copy of any backend
+    pub fn from_view<G: WeightedGraph>(graph: &G) -> Self {
+        let n = graph.vertex_count();
+        let m = graph.edge_count();
+        let mut offsets = Vec::with_capacity(n + 1);
+        let mut targets = Vec::with_capacity(m);
+        let mut weights = Vec::with_capacity(m);
+
+        offsets.push(0);
+        for u in 0..n {
+            for (v, w) in graph.out_neighbors(u) {
+                targets.push(v);
+                weights.push(w);
+            }
+            offsets.push(targets.len());
+        }
+
+        CsrGraph { n, offsets, targets, weights }
+    }
+
+    pub fn m(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Out-edges of `u` as (target, weight) pairs
+    pub fn neighbors(&self, u: usize) -> CsrNeighbors<'_> {
+        let range = self.offsets[u]..self.offsets[u + 1];
+        self.targets[range.clone()].iter().copied().zip(self.weights[range].iter().copied())
+    }
+}
+
+pub type CsrNeighbors<'a> = Zip<Copied<Iter<'a, usize>>, Copied<Iter<'a, f64>>>;
+
+impl WeightedGraph for CsrGraph {
+    type Neighbors<'a> = CsrNeighbors<'a>;
+
+    fn vertex_count(&self) -> usize {
+        self.n
+    }
+
+    fn out_neighbors(&self, u: usize) -> CsrNeighbors<'_> {
+        self.neighbors(u)
+    }
+
+    fn edge_count(&self) -> usize {
+        self.m()
+    }
+
+    fn out_degree(&self, u: usize) -> usize {
+        self.offsets[u + 1] - self.offsets[u]
+    }
+}
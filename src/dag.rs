@@ -0,0 +1,32 @@
+use crate::graph::WeightedGraph;
+
+/// Shortest paths on a DAG by relaxing vertices in topological order,
+/// O(n + m). Negative weights are fine since there are no cycles.
+/// Returns `None` if the graph has a cycle.
+pub fn dag_shortest_paths<G: WeightedGraph>(graph: &G, source: usize) -> Option<Vec<f64>> {
+    let order = graph.topological_order()?;
+    Some(dag_shortest_paths_with_order(graph, &order, source))
+}
+
+/// Same as `dag_shortest_paths` with a precomputed topological order
+pub fn dag_shortest_paths_with_order<G: WeightedGraph>(graph: &G, order: &[usize], source: usize) -> Vec<f64> {
+    let mut dist = vec![f64::INFINITY; graph.vertex_count()];
+    dist[source] = 0.0;
+
+    // Vertices before the source in the order are unreachable from it
+    let start = order.iter().position(|&v| v == source).unwrap_or(order.len());
+    for &u in &order[start..] {
+        let du = dist[u];
+        if du == f64::INFINITY {
+            continue;
+        }
+        for (to, weight) in graph.out_neighbors(u) {
+            let new_dist = du + weight;
+            if new_dist < dist[to] {
+                dist[to] = new_dist;
+            }
+        }
+    }
+
+    dist
+}
@@ -0,0 +1,44 @@
+use crate::graph::WeightedGraph;
+use crate::addressable_heap::{AddressableQueue, DaryHeap, FibonacciHeap, PairingHeap};
+
+/// Textbook Dijkstra: each vertex is in the queue at most once and improved
+/// distances use decrease-key instead of duplicate entries
+pub fn dijkstra_with_heap<H: AddressableQueue>(graph: &impl WeightedGraph, source: usize) -> Vec<f64> {
+    let n = graph.vertex_count();
+    let mut dist = vec![f64::INFINITY; n];
+    let mut heap = H::with_capacity(n);
+
+    dist[source] = 0.0;
+    heap.insert(source, 0.0);
+
+    while let Some((u, d)) = heap.pop_min() {
+        for (to, weight) in graph.out_neighbors(u) {
+            let new_dist = d + weight;
+
+            if new_dist < dist[to] {
+                if heap.contains(to) {
+                    heap.decrease_key(to, new_dist);
+                } else {
+                    heap.insert(to, new_dist);
+                }
+                dist[to] = new_dist;
+            }
+        }
+    }
+
+    dist
+}
+
+/// Dijkstra with a Fibonacci heap, O(m + n log n)
+pub fn fibonacci_dijkstra<G: WeightedGraph>(graph: &G, source: usize) -> Vec<f64> {
+    dijkstra_with_heap::<FibonacciHeap>(graph, source)
+}
+
+pub fn pairing_dijkstra<G: WeightedGraph>(graph: &G, source: usize) -> Vec<f64> {
+    dijkstra_with_heap::<PairingHeap>(graph, source)
+}
+
+/// Dijkstra with an indexed 4-ary heap
+pub fn dary_dijkstra<G: WeightedGraph>(graph: &G, source: usize) -> Vec<f64> {
+    dijkstra_with_heap::<DaryHeap<4>>(graph, source)
+}
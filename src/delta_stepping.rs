@@ -0,0 +1,157 @@
+use crate::graph::WeightedGraph;
+
+/// Edges of each vertex split by weight: light (w <= Δ) edges are relaxed
+/// repeatedly inside a bucket phase, heavy ones once per settled vertex
+///
+/// Δ below `max_weight / (n + 1)` is raised to that value, which keeps the
+/// bucket array at most n + 3 slots long without affecting correctness.
+pub struct EdgeSplit {
+    pub delta: f64,
+    pub light: Vec<Vec<(usize, f64)>>,
+    pub heavy: Vec<Vec<(usize, f64)>>,
+    pub max_weight: f64,
+}
+
+impl EdgeSplit {
+    /// # Panics
+    ///
+    /// Panics if `delta` is not positive, or if a weight is negative, NaN or
+    /// infinite.
+    pub fn new<G: WeightedGraph>(graph: &G, delta: f64) -> Self {
+        assert!(delta > 0.0, "delta must be positive");
+        let n = graph.vertex_count();
+        let mut max_weight = 0.0f64;
+        for u in 0..n {
+            for (_, weight) in graph.out_neighbors(u) {
+                assert!(weight >= 0.0 && weight.is_finite(),
+                        "delta-stepping needs finite non-negative weights, found {}", weight);
+                max_weight = max_weight.max(weight);
+            }
+        }
+        let delta = delta.max(max_weight / (n as f64 + 1.0));
+
+        let mut light = vec![Vec::new(); n];
+        let mut heavy = vec![Vec::new(); n];
+        for u in 0..n {
+            for (to, weight) in graph.out_neighbors(u) {
+                if weight <= delta {
+                    light[u].push((to, weight));
+                } else {
+                    heavy[u].push((to, weight));
+                }
+            }
+        }
+
+        EdgeSplit { delta, light, heavy, max_weight }
+    }
+}
+
+/// Δ heuristic from Meyer & Sanders: for weights spread up to `max_weight`
+/// with average out-degree d, Δ = max_weight / d keeps the number of
+/// re-relaxations per bucket O(1) while avoiding too many empty buckets.
+/// Graphs without edges or with only zero weights get Δ = 1.
+pub fn auto_delta<G: WeightedGraph>(graph: &G) -> f64 {
+    let n = graph.vertex_count();
+    let (mut m, mut max_weight) = (0usize, 0.0f64);
+    for u in 0..n {
+        for (_, weight) in graph.out_neighbors(u) {
+            m += 1;
+            max_weight = max_weight.max(weight);
+        }
+    }
+    if m == 0 {
+        return 1.0;
+    }
+
+    let avg_degree = m as f64 / n as f64;
+    let delta = max_weight / avg_degree.max(1.0);
+    if delta > 0.0 { delta } else { 1.0 }
+}
+
+/// Delta-stepping with Δ chosen by `auto_delta`
+pub fn delta_stepping<G: WeightedGraph>(graph: &G, source: usize) -> Vec<f64> {
+    delta_stepping_with_delta(graph, source, auto_delta(graph))
+}
+
+/// # Panics
+///
+/// See `EdgeSplit::new`.
+pub fn delta_stepping_with_delta<G: WeightedGraph>(graph: &G, source: usize, delta: f64) -> Vec<f64> {
+    delta_stepping_split(&EdgeSplit::new(graph, delta), source)
+}
+
+/// Runs delta-stepping on a precomputed split, so the split can be shared
+/// across sources
+pub fn delta_stepping_split(split: &EdgeSplit, source: usize) -> Vec<f64> {
+    let n = split.light.len();
+    let delta = split.delta;
+    let mut dist = vec![f64::INFINITY; n];
+
+    // Tentative distances never exceed the current bucket by more than the
+    // heaviest edge, so a cyclic array of buckets is enough
+    let span = (split.max_weight / delta).floor();
+    assert!(span <= n as f64 + 1.0, "delta too small for the heaviest edge; build the split with EdgeSplit::new");
+    let slots = span as usize + 2;
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); slots];
+    let mut pending = 0usize;
+
+    let bucket_of = |d: f64| (d / delta).floor() as usize;
+
+    dist[source] = 0.0;
+    buckets[0].push(source);
+    pending += 1;
+
+    let mut in_settled = vec![false; n];
+    let mut current = 0usize;
+
+    while pending > 0 {
+        // Advance to the next non-empty bucket
+        while buckets[current % slots].is_empty() {
+            current += 1;
+        }
+
+        let mut settled = Vec::new();
+
+        // Light-edge phase: may refill the current bucket
+        while !buckets[current % slots].is_empty() {
+            let requests = std::mem::take(&mut buckets[current % slots]);
+            pending -= requests.len();
+
+            for u in requests {
+                if bucket_of(dist[u]) != current {
+                    continue;
+                }
+                if !in_settled[u] {
+                    in_settled[u] = true;
+                    settled.push(u);
+                }
+
+                for &(v, w) in &split.light[u] {
+                    let new_dist = dist[u] + w;
+                    if new_dist < dist[v] {
+                        dist[v] = new_dist;
+                        buckets[bucket_of(new_dist) % slots].push(v);
+                        pending += 1;
+                    }
+                }
+            }
+        }
+
+        // Heavy edges leave the current bucket, so one pass suffices
+        for &u in &settled {
+            in_settled[u] = false;
+            for &(v, w) in &split.heavy[u] {
+                let new_dist = dist[u] + w;
+                if new_dist < dist[v] {
+                    dist[v] = new_dist;
+                    buckets[bucket_of(new_dist) % slots].push(v);
+                    pending += 1;
+                }
+            }
+        }
+
+        current += 1;
+    }
+
+    dist
+}
@@ -1,4 +1,5 @@
-use crate::graph::Graph;
+use crate::graph::WeightedGraph;
+use crate::workspace::EpochVec;
 use std::collections::BinaryHeap;
 use std::cmp::Ordering;
 
@@ -18,18 +19,34 @@ impl Eq for Node {}
 
 impl PartialOrd for Node {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.dist.partial_cmp(&self.dist)
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Node {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+        other.dist.partial_cmp(&self.dist).unwrap_or(Ordering::Equal)
     }
 }
 
-pub fn dijkstra(graph: &Graph, source: usize) -> Vec<f64> {
-    let n = graph.n;
+/// Work done by a search, for fitting growth against complexity models
+/// (see `regression`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OperationCounts {
+    /// Edges scanned from settled vertices
+    pub relaxations: u64,
+    pub heap_pushes: u64,
+    pub heap_pops: u64,
+}
+
+impl OperationCounts {
+    pub fn total(&self) -> u64 {
+        self.relaxations + self.heap_pushes + self.heap_pops
+    }
+}
+
+pub fn dijkstra<G: WeightedGraph>(graph: &G, source: usize) -> Vec<f64> {
+    let n = graph.vertex_count();
     let mut dist = vec![f64::INFINITY; n];
     let mut heap = BinaryHeap::new();
     
@@ -41,15 +58,113 @@ pub fn dijkstra(graph: &Graph, source: usize) -> Vec<f64> {
             continue;
         }
         
-        for edge in &graph.edges[u] {
-            let new_dist = dist[u] + edge.weight;
+        for (to, weight) in graph.out_neighbors(u) {
+            let new_dist = dist[u] + weight;
             
-            if new_dist < dist[edge.to] {
-                dist[edge.to] = new_dist;
-                heap.push(Node { id: edge.to, dist: new_dist });
+            if new_dist < dist[to] {
+                dist[to] = new_dist;
+                heap.push(Node { id: to, dist: new_dist });
             }
         }
     }
     
     dist
-}
\ No newline at end of file
+}
+
+/// `dijkstra` that also counts its operations. Kept separate so the plain
+/// version stays an uninstrumented baseline.
+pub fn dijkstra_with_counts<G: WeightedGraph>(graph: &G, source: usize) -> (Vec<f64>, OperationCounts) {
+    let n = graph.vertex_count();
+    let mut dist = vec![f64::INFINITY; n];
+    let mut heap = BinaryHeap::new();
+    let mut counts = OperationCounts::default();
+    
+    dist[source] = 0.0;
+    heap.push(Node { id: source, dist: 0.0 });
+    counts.heap_pushes += 1;
+    
+    while let Some(Node { id: u, dist: d }) = heap.pop() {
+        counts.heap_pops += 1;
+        if d > dist[u] {
+            continue;
+        }
+        
+        for (to, weight) in graph.out_neighbors(u) {
+            counts.relaxations += 1;
+            let new_dist = dist[u] + weight;
+            
+            if new_dist < dist[to] {
+                dist[to] = new_dist;
+                heap.push(Node { id: to, dist: new_dist });
+                counts.heap_pushes += 1;
+            }
+        }
+    }
+    
+    (dist, counts)
+}
+
+/// Buffers for repeated Dijkstra queries on graphs of up to `n` vertices.
+/// Distances are reset lazily and the heap keeps its capacity, so after
+/// the first few queries `run` does not allocate.
+pub struct DijkstraWorkspace {
+    dist: EpochVec<f64>,
+    /// Vertex count of the last graph searched
+    n: usize,
+    heap: BinaryHeap<Node>,
+    reached: Vec<usize>,
+}
+
+impl DijkstraWorkspace {
+    pub fn new(n: usize) -> Self {
+        DijkstraWorkspace { dist: EpochVec::new(n, f64::INFINITY), n, heap: BinaryHeap::new(), reached: Vec::new() }
+    }
+    
+    /// Same search as `dijkstra`, leaving the result in the workspace
+    pub fn run<G: WeightedGraph>(&mut self, graph: &G, source: usize) {
+        let n = graph.vertex_count();
+        assert!(n <= self.dist.len(), "workspace for {} vertices, graph has {}", self.dist.len(), n);
+        self.n = n;
+        self.dist.reset();
+        self.heap.clear();
+        self.reached.clear();
+        
+        self.dist.set(source, 0.0);
+        self.reached.push(source);
+        self.heap.push(Node { id: source, dist: 0.0 });
+        
+        while let Some(Node { id: u, dist: d }) = self.heap.pop() {
+            if d > self.dist.get(u) {
+                continue;
+            }
+            
+            for (to, weight) in graph.out_neighbors(u) {
+                let new_dist = d + weight;
+                
+                if new_dist < self.dist.get(to) {
+                    if !self.dist.is_set(to) {
+                        self.reached.push(to);
+                    }
+                    self.dist.set(to, new_dist);
+                    self.heap.push(Node { id: to, dist: new_dist });
+                }
+            }
+        }
+    }
+    
+    /// Distance from the last source, infinite if unreachable
+    pub fn distance(&self, v: usize) -> f64 {
+        self.dist.get(v)
+    }
+    
+    /// Vertices reached by the last query, in discovery order
+    pub fn reached(&self) -> &[usize] {
+        &self.reached
+    }
+    
+    /// All distances from the last query, as `dijkstra` returns them: one
+    /// per vertex of that graph, however large the workspace
+    pub fn distances(&self) -> Vec<f64> {
+        (0..self.n).map(|v| self.dist.get(v)).collect()
+    }
+}
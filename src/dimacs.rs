@@ -0,0 +1,138 @@
+use crate::graph::Graph;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+/// Reads a DIMACS shortest-path graph (`.gr`): a `p sp n m` header followed by
+/// `a u v w` arc lines with 1-based vertex ids.
+pub fn read_graph<P: AsRef<Path>>(path: P) -> io::Result<Graph> {
+    parse_graph(BufReader::new(File::open(path)?))
+}
+
+/// Reads a DIMACS coordinate file (`.co`): a `p aux sp co n` header followed
+/// by `v id x y` lines with 1-based vertex ids.
+pub fn read_coordinates<P: AsRef<Path>>(path: P) -> io::Result<Vec<(f64, f64)>> {
+    parse_coordinates(BufReader::new(File::open(path)?))
+}
+
+/// Reads a `.gr` file and, if given, attaches the coordinates from a `.co` file
+pub fn read_graph_with_coordinates<P: AsRef<Path>, Q: AsRef<Path>>(gr: P, co: Option<Q>) -> io::Result<Graph> {
+    let mut graph = read_graph(gr)?;
+    if let Some(co) = co {
+        let coords = read_coordinates(co)?;
+        if coords.len() != graph.n {
+            return Err(invalid(format!("coordinate file has {} vertices, graph has {}", coords.len(), graph.n)));
+        }
+        graph.coords = Some(coords);
+    }
+    Ok(graph)
+}
+
+/// Writes a graph as a DIMACS `.gr` file. Weights are written with full
+/// precision, so reading the file back gives the same graph.
+pub fn write_graph<P: AsRef<Path>>(graph: &Graph, path: P) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    format_graph(graph, &mut writer)?;
+    writer.flush()
+}
+
+/// Writes the graph's coordinates as a DIMACS `.co` file
+pub fn write_coordinates<P: AsRef<Path>>(coords: &[(f64, f64)], path: P) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    format_coordinates(coords, &mut writer)?;
+    writer.flush()
+}
+
+pub fn format_graph<W: Write>(graph: &Graph, writer: &mut W) -> io::Result<()> {
+    writeln!(writer, "p sp {} {}", graph.n, graph.m())?;
+    for (u, edges) in graph.edges.iter().enumerate() {
+        for edge in edges {
+            writeln!(writer, "a {} {} {}", u + 1, edge.to + 1, edge.weight)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn format_coordinates<W: Write>(coords: &[(f64, f64)], writer: &mut W) -> io::Result<()> {
+    writeln!(writer, "p aux sp co {}", coords.len())?;
+    for (v, &(x, y)) in coords.iter().enumerate() {
+        writeln!(writer, "v {} {} {}", v + 1, x, y)?;
+    }
+    Ok(())
+}
+
+pub fn parse_graph<R: BufRead>(reader: R) -> io::Result<Graph> {
+    let mut graph: Option<Graph> = None;
+
+    for line in reader.lines() {
+        let line = line?;
+        let fields: Vec<&str> = line.split_whitespace().collect();
+
+        match fields.first() {
+            Some(&"p") => {
+                if fields.len() < 4 || fields[1] != "sp" {
+                    return Err(invalid(format!("bad problem line: {}", line)));
+                }
+                graph = Some(Graph::new(parse_field(fields[2])?));
+            }
+            Some(&"a") => {
+                let graph = graph.as_mut().ok_or_else(|| invalid("arc before problem line".to_string()))?;
+                if fields.len() < 4 {
+                    return Err(invalid(format!("bad arc line: {}", line)));
+                }
+                let from = vertex_index(parse_field(fields[1])?, graph.n)?;
+                let to = vertex_index(parse_field(fields[2])?, graph.n)?;
+                let weight: f64 = parse_field(fields[3])?;
+                graph.add_edge(from, to, weight);
+            }
+            // Comments ("c") and blank lines
+            _ => {}
+        }
+    }
+
+    graph.ok_or_else(|| invalid("missing problem line".to_string()))
+}
+
+pub fn parse_coordinates<R: BufRead>(reader: R) -> io::Result<Vec<(f64, f64)>> {
+    let mut coords: Option<Vec<(f64, f64)>> = None;
+
+    for line in reader.lines() {
+        let line = line?;
+        let fields: Vec<&str> = line.split_whitespace().collect();
+
+        match fields.first() {
+            Some(&"p") => {
+                if fields.len() < 5 || fields[3] != "co" {
+                    return Err(invalid(format!("bad problem line: {}", line)));
+                }
+                coords = Some(vec![(0.0, 0.0); parse_field(fields[4])?]);
+            }
+            Some(&"v") => {
+                let coords = coords.as_mut().ok_or_else(|| invalid("vertex before problem line".to_string()))?;
+                if fields.len() < 4 {
+                    return Err(invalid(format!("bad vertex line: {}", line)));
+                }
+                let v = vertex_index(parse_field(fields[1])?, coords.len())?;
+                coords[v] = (parse_field(fields[2])?, parse_field(fields[3])?);
+            }
+            _ => {}
+        }
+    }
+
+    coords.ok_or_else(|| invalid("missing problem line".to_string()))
+}
+
+fn parse_field<T: std::str::FromStr>(field: &str) -> io::Result<T> {
+    field.parse().map_err(|_| invalid(format!("cannot parse '{}'", field)))
+}
+
+fn vertex_index(id: usize, n: usize) -> io::Result<usize> {
+    if id == 0 || id > n {
+        return Err(invalid(format!("vertex id {} out of range 1..={}", id, n)));
+    }
+    Ok(id - 1)
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
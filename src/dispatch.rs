@@ -0,0 +1,53 @@
+use crate::graph::WeightedGraph;
+use crate::dijkstra::dijkstra;
+use crate::bellman_ford::{bellman_ford, NegativeCycle};
+use crate::dag::dag_shortest_paths_with_order;
+
+/// Algorithm picked by `shortest_paths`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    Dag,
+    Dijkstra,
+    BellmanFord,
+}
+
+impl Algorithm {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Dag => "dag",
+            Algorithm::Dijkstra => "dijkstra",
+            Algorithm::BellmanFord => "bellman-ford",
+        }
+    }
+}
+
+/// Distances together with the algorithm that produced them
+#[derive(Debug, Clone)]
+pub struct Solution {
+    pub algorithm: Algorithm,
+    pub dist: Vec<f64>,
+}
+
+/// Picks the cheapest algorithm that is correct for this graph
+pub fn choose_algorithm<G: WeightedGraph>(graph: &G) -> Algorithm {
+    if graph.is_acyclic() {
+        Algorithm::Dag
+    } else if graph.has_negative_weights() {
+        Algorithm::BellmanFord
+    } else {
+        Algorithm::Dijkstra
+    }
+}
+
+/// Single-source shortest paths on any graph. Acyclic graphs (with any
+/// weights) are solved in linear time in topological order; other
+/// negative-weight graphs are routed to Bellman-Ford, which reports a
+/// reachable negative cycle as an error.
+pub fn shortest_paths<G: WeightedGraph>(graph: &G, source: usize) -> Result<Solution, NegativeCycle> {
+    let (algorithm, dist) = match graph.topological_order() {
+        Some(order) => (Algorithm::Dag, dag_shortest_paths_with_order(graph, &order, source)),
+        None if graph.has_negative_weights() => (Algorithm::BellmanFord, bellman_ford(graph, source)?),
+        None => (Algorithm::Dijkstra, dijkstra(graph, source)),
+    };
+    Ok(Solution { algorithm, dist })
+}
@@ -0,0 +1,72 @@
+use crate::graph::WeightedGraph;
+
+const NONE: usize = usize::MAX;
+
+/// All-pairs distances by Floyd-Warshall, O(n^3). Meant as a ground-truth
+/// oracle for small graphs: it shares no code with the Dijkstra family and
+/// handles negative weights.
+pub struct FloydWarshall {
+    pub dist: Vec<Vec<f64>>,
+    /// `next[s][t]` is the vertex after `s` on a shortest s -> t path
+    next: Vec<Vec<usize>>,
+}
+
+impl FloydWarshall {
+    pub fn new<G: WeightedGraph>(graph: &G) -> Self {
+        let n = graph.vertex_count();
+        let mut dist = vec![vec![f64::INFINITY; n]; n];
+        let mut next = vec![vec![NONE; n]; n];
+
+        for v in 0..n {
+            dist[v][v] = 0.0;
+            next[v][v] = v;
+        }
+        for u in 0..n {
+            for (to, weight) in graph.out_neighbors(u) {
+                if weight < dist[u][to] {
+                    dist[u][to] = weight;
+                    next[u][to] = to;
+                }
+            }
+        }
+
+        for k in 0..n {
+            for i in 0..n {
+                let dik = dist[i][k];
+                if dik == f64::INFINITY {
+                    continue;
+                }
+                for j in 0..n {
+                    let new_dist = dik + dist[k][j];
+                    if new_dist < dist[i][j] {
+                        dist[i][j] = new_dist;
+                        next[i][j] = next[i][k];
+                    }
+                }
+            }
+        }
+
+        FloydWarshall { dist, next }
+    }
+
+    /// A vertex lies on a negative cycle iff its distance to itself is negative
+    pub fn has_negative_cycle(&self) -> bool {
+        self.dist.iter().enumerate().any(|(v, row)| row[v] < 0.0)
+    }
+
+    /// Vertices of a shortest path from `source` to `target`, or `None` if
+    /// unreachable. Only meaningful without negative cycles.
+    pub fn path(&self, source: usize, target: usize) -> Option<Vec<usize>> {
+        if self.next[source][target] == NONE {
+            return None;
+        }
+
+        let mut path = vec![source];
+        let mut v = source;
+        while v != target {
+            v = self.next[v][target];
+            path.push(v);
+        }
+        Some(path)
+    }
+}
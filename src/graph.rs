@@ -9,6 +9,120 @@ pub struct Edge {
 pub struct Graph {
     pub n: usize,
     pub edges: Vec<Vec<Edge>>,
+    /// Planar node positions, present for geometric or DIMACS `.co` inputs
+    pub coords: Option<Vec<(f64, f64)>>,
+}
+
+/// Read-only access to a directed graph with `f64` weights, independent
+/// of how it is stored. Implemented by `Graph`, `csr::CsrGraph`,
+/// `mmap_graph::MmapGraph` and the implicit graphs in `implicit`; the
+/// registry solvers (see `solver::Solver::run_on`) accept any of them.
+pub trait WeightedGraph {
+    type Neighbors<'a>: Iterator<Item = (usize, f64)>
+    where
+        Self: 'a;
+    
+    fn vertex_count(&self) -> usize;
+    
+    /// Out-edges of `u` as (target, weight) pairs
+    fn out_neighbors(&self, u: usize) -> Self::Neighbors<'_>;
+    
+    fn edge_count(&self) -> usize {
+        (0..self.vertex_count()).map(|u| self.out_degree(u)).sum()
+    }
+    
+    fn out_degree(&self, u: usize) -> usize {
+        self.out_neighbors(u).count()
+    }
+    
+    /// In-edges of `v` as (source, weight) pairs, for backends that store
+    /// or can compute them; `None` otherwise
+    fn in_neighbors(&self, _v: usize) -> Option<Self::Neighbors<'_>> {
+        None
+    }
+    
+    /// Planar vertex positions, for backends that carry them
+    fn coords(&self) -> Option<&[(f64, f64)]> {
+        None
+    }
+    
+    /// Whether any edge weight is negative; such graphs need Bellman-Ford
+    /// unless acyclic (see `dispatch::shortest_paths`). Scans every edge,
+    /// so callers checking repeatedly should keep the answer.
+    fn has_negative_weights(&self) -> bool {
+        (0..self.vertex_count()).any(|u| self.out_neighbors(u).any(|(_, w)| w < 0.0))
+    }
+    
+    /// Vertices in topological order (Kahn's algorithm), or `None` if the
+    /// graph has a cycle
+    fn topological_order(&self) -> Option<Vec<usize>> {
+        let n = self.vertex_count();
+        let mut in_degree = vec![0usize; n];
+        for u in 0..n {
+            for (v, _) in self.out_neighbors(u) {
+                in_degree[v] += 1;
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).filter(|&v| in_degree[v] == 0).collect();
+        let mut head = 0;
+        while head < order.len() {
+            let u = order[head];
+            head += 1;
+            for (v, _) in self.out_neighbors(u) {
+                in_degree[v] -= 1;
+                if in_degree[v] == 0 {
+                    order.push(v);
+                }
+            }
+        }
+
+        (order.len() == n).then_some(order)
+    }
+    
+    fn is_acyclic(&self) -> bool {
+        self.topological_order().is_some()
+    }
+}
+
+/// Out-edges of a `Graph` vertex as (target, weight) pairs
+#[derive(Debug, Clone)]
+pub struct EdgeIter<'a>(std::slice::Iter<'a, Edge>);
+
+impl Iterator for EdgeIter<'_> {
+    type Item = (usize, f64);
+    
+    fn next(&mut self) -> Option<(usize, f64)> {
+        self.0.next().map(|edge| (edge.to, edge.weight))
+    }
+    
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl WeightedGraph for Graph {
+    type Neighbors<'a> = EdgeIter<'a>;
+    
+    fn vertex_count(&self) -> usize {
+        self.n
+    }
+    
+    fn out_neighbors(&self, u: usize) -> EdgeIter<'_> {
+        EdgeIter(self.edges[u].iter())
+    }
+    
+    fn edge_count(&self) -> usize {
+        self.m()
+    }
+    
+    fn out_degree(&self, u: usize) -> usize {
+        self.edges[u].len()
+    }
+    
+    fn coords(&self) -> Option<&[(f64, f64)]> {
+        self.coords.as_deref()
+    }
 }
 
 impl Graph {
@@ -16,9 +130,36 @@ impl Graph {
         Graph {
             n,
             edges: vec![vec![]; n],
+            coords: None,
         }
     }
 
+    /// Copies any backend into adjacency lists, e.g. for the solvers that
+    /// need to own or mutate their graph
+    pub fn from_view<G: WeightedGraph>(view: &G) -> Self {
+        let mut graph = Graph::new(view.vertex_count());
+        for u in 0..graph.n {
+            for (v, w) in view.out_neighbors(u) {
+                graph.add_edge(u, v, w);
+            }
+        }
+        graph.coords = view.coords().map(<[_]>::to_vec);
+        graph
+    }
+    
+    /// Adjacency lists of any backend with every edge reversed, for
+    /// backward searches
+    pub fn from_reverse<G: WeightedGraph>(view: &G) -> Self {
+        let mut reversed = Graph::new(view.vertex_count());
+        for u in 0..reversed.n {
+            for (v, w) in view.out_neighbors(u) {
+                reversed.add_edge(v, u, w);
+            }
+        }
+        reversed.coords = view.coords().map(<[_]>::to_vec);
+        reversed
+    }
+    
     pub fn add_edge(&mut self, from: usize, to: usize, weight: f64) {
         self.edges[from].push(Edge { to, weight });
     }
@@ -27,9 +168,78 @@ impl Graph {
         self.edges.iter().map(|e| e.len()).sum()
     }
 
+    /// Graph with every edge reversed, used for backward searches
+    pub fn reverse(&self) -> Self {
+        Graph::from_reverse(self)
+    }
+
+    /// See `WeightedGraph::topological_order`; callable without the trait
+    /// in scope
+    pub fn topological_order(&self) -> Option<Vec<usize>> {
+        WeightedGraph::topological_order(self)
+    }
+
+    pub fn is_acyclic(&self) -> bool {
+        WeightedGraph::is_acyclic(self)
+    }
+
+    /// Some directed cycle as a vertex sequence (the closing edge goes from
+    /// the last vertex back to the first), found by iterative DFS
+    pub fn find_cycle(&self) -> Option<Vec<usize>> {
+        const WHITE: u8 = 0;
+        const GRAY: u8 = 1;
+        const BLACK: u8 = 2;
+
+        let mut color = vec![WHITE; self.n];
+        let mut parent = vec![usize::MAX; self.n];
+
+        for root in 0..self.n {
+            if color[root] != WHITE {
+                continue;
+            }
+            color[root] = GRAY;
+            // (vertex, index of the next out-edge to look at)
+            let mut stack = vec![(root, 0usize)];
+
+            while let Some(&mut (u, ref mut next)) = stack.last_mut() {
+                if let Some(edge) = self.edges[u].get(*next) {
+                    *next += 1;
+                    let v = edge.to;
+                    match color[v] {
+                        WHITE => {
+                            color[v] = GRAY;
+                            parent[v] = u;
+                            stack.push((v, 0));
+                        }
+                        GRAY => {
+                            // Back edge u -> v: walk the DFS path from u up to v
+                            let mut cycle = vec![u];
+                            let mut w = u;
+                            while w != v {
+                                w = parent[w];
+                                cycle.push(w);
+                            }
+                            cycle.reverse();
+                            return Some(cycle);
+                        }
+                        _ => {}
+                    }
+                } else {
+                    color[u] = BLACK;
+                    stack.pop();
+                }
+            }
+        }
+
+        None
+    }
+
     pub fn generate_random(n: usize, density: f64, max_weight: f64) -> Self {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
+        Self::generate_random_with_rng(&mut rand::thread_rng(), n, density, max_weight)
+    }
+    
+    /// `generate_random` with a caller-provided RNG, for reproducible graphs
+    pub fn generate_random_with_rng<R: rand::Rng>(rng: &mut R, n: usize, density: f64, max_weight: f64) -> Self {
         let mut graph = Graph::new(n);
         
         for i in 0..n {
@@ -43,4 +253,107 @@ impl Graph {
         
         graph
     }
-}
\ No newline at end of file
+
+    /// Random geometric graph: points in the unit square, edges in both
+    /// directions between points closer than `radius`. Weights are the
+    /// Euclidean distance stretched by a random factor in [1, 2), so the
+    /// straight-line distance is always a lower bound.
+    pub fn generate_geometric(n: usize, radius: f64) -> Self {
+        Self::generate_geometric_with_rng(&mut rand::thread_rng(), n, radius)
+    }
+    
+    /// `generate_geometric` with a caller-provided RNG
+    pub fn generate_geometric_with_rng<R: rand::Rng>(rng: &mut R, n: usize, radius: f64) -> Self {
+        let mut graph = Graph::new(n);
+        
+        let coords: Vec<(f64, f64)> = (0..n).map(|_| (rng.gen::<f64>(), rng.gen::<f64>())).collect();
+        
+        for i in 0..n {
+            for j in 0..n {
+                let dx = coords[i].0 - coords[j].0;
+                let dy = coords[i].1 - coords[j].1;
+                let d = (dx * dx + dy * dy).sqrt();
+                if i != j && d < radius {
+                    let weight = d * (1.0 + rng.gen::<f64>());
+                    graph.add_edge(i, j, weight);
+                }
+            }
+        }
+        
+        graph.coords = Some(coords);
+        graph
+    }
+    
+    /// rows x cols grid with edges both ways between 4-neighbours and
+    /// random weights in [0, max_weight). Vertex r * cols + c sits at
+    /// (c, r) scaled into the unit square.
+    pub fn generate_grid(rows: usize, cols: usize, max_weight: f64) -> Self {
+        Self::generate_grid_with_rng(&mut rand::thread_rng(), rows, cols, max_weight)
+    }
+    
+    pub fn generate_grid_with_rng<R: rand::Rng>(rng: &mut R, rows: usize, cols: usize, max_weight: f64) -> Self {
+        let mut graph = Graph::new(rows * cols);
+        let id = |r: usize, c: usize| r * cols + c;
+        
+        for r in 0..rows {
+            for c in 0..cols {
+                if c + 1 < cols {
+                    graph.add_edge(id(r, c), id(r, c + 1), rng.gen::<f64>() * max_weight);
+                    graph.add_edge(id(r, c + 1), id(r, c), rng.gen::<f64>() * max_weight);
+                }
+                if r + 1 < rows {
+                    graph.add_edge(id(r, c), id(r + 1, c), rng.gen::<f64>() * max_weight);
+                    graph.add_edge(id(r + 1, c), id(r, c), rng.gen::<f64>() * max_weight);
+                }
+            }
+        }
+        
+        let scale = (rows.max(cols).max(2) - 1) as f64;
+        graph.coords = Some((0..rows * cols).map(|v| ((v % cols) as f64 / scale, (v / cols) as f64 / scale)).collect());
+        graph
+    }
+    
+    /// Scale-free graph by Barabási-Albert preferential attachment: starting
+    /// from a clique on `attach + 1` vertices, each new vertex links to
+    /// `attach` distinct earlier vertices chosen with probability
+    /// proportional to their degree. Edges go both ways with random weights
+    /// in [0, max_weight).
+    pub fn generate_scale_free(n: usize, attach: usize, max_weight: f64) -> Self {
+        Self::generate_scale_free_with_rng(&mut rand::thread_rng(), n, attach, max_weight)
+    }
+    
+    pub fn generate_scale_free_with_rng<R: rand::Rng>(rng: &mut R, n: usize, attach: usize, max_weight: f64) -> Self {
+        let mut graph = Graph::new(n);
+        // Each vertex appears once per incident edge, so a uniform pick
+        // from this list is a degree-proportional pick
+        let mut endpoints = Vec::new();
+        let core = (attach + 1).min(n);
+        
+        for i in 0..core {
+            for j in 0..i {
+                graph.add_edge(i, j, rng.gen::<f64>() * max_weight);
+                graph.add_edge(j, i, rng.gen::<f64>() * max_weight);
+                endpoints.push(i);
+                endpoints.push(j);
+            }
+        }
+        
+        for v in core..n {
+            let mut targets = Vec::with_capacity(attach);
+            while targets.len() < attach {
+                let t = endpoints[rng.gen_range(0..endpoints.len())];
+                if !targets.contains(&t) {
+                    targets.push(t);
+                }
+            }
+            for t in targets {
+                graph.add_edge(v, t, rng.gen::<f64>() * max_weight);
+                graph.add_edge(t, v, rng.gen::<f64>() * max_weight);
+                endpoints.push(v);
+                endpoints.push(t);
+            }
+        }
+        
+        graph
+    }
+}
@@ -0,0 +1,158 @@
+use crate::graph::WeightedGraph;
+
+/// Deterministic weight in [0, max_weight) of the edge `from -> to`, so
+/// implicit graphs need no storage and give the same weight on every visit
+fn edge_weight(seed: u64, from: usize, to: usize, max_weight: f64) -> f64 {
+    let hash = splitmix64(splitmix64(seed ^ from as u64) ^ to as u64);
+    (hash >> 11) as f64 / (1u64 << 53) as f64 * max_weight
+}
+
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// `rows` x `cols` grid with 4-neighbour edges in both directions, as
+/// `Graph::generate_grid` builds it, but computed on demand: vertex
+/// `r * cols + c` sits at row r, column c. Memory stays O(1) however large
+/// the grid.
+#[derive(Debug, Clone, Copy)]
+pub struct GridGraph {
+    pub rows: usize,
+    pub cols: usize,
+    pub max_weight: f64,
+    pub seed: u64,
+}
+
+impl GridGraph {
+    pub fn new(rows: usize, cols: usize, max_weight: f64, seed: u64) -> Self {
+        GridGraph { rows, cols, max_weight, seed }
+    }
+
+    fn neighbors(&self, u: usize, incoming: bool) -> GridNeighbors<'_> {
+        GridNeighbors { grid: self, u, direction: 0, incoming }
+    }
+}
+
+/// Edges of a `GridGraph` vertex as (neighbour, weight) pairs
+pub struct GridNeighbors<'a> {
+    grid: &'a GridGraph,
+    u: usize,
+    /// Next direction to try: right, down, left, up
+    direction: u8,
+    /// Yield the weights of edges into `u` rather than out of it
+    incoming: bool,
+}
+
+impl Iterator for GridNeighbors<'_> {
+    type Item = (usize, f64);
+
+    fn next(&mut self) -> Option<(usize, f64)> {
+        let GridGraph { rows, cols, max_weight, seed } = *self.grid;
+        let (r, c) = (self.u / cols, self.u % cols);
+        while self.direction < 4 {
+            let direction = self.direction;
+            self.direction += 1;
+            let v = match direction {
+                0 if c + 1 < cols => self.u + 1,
+                1 if r + 1 < rows => self.u + cols,
+                2 if c > 0 => self.u - 1,
+                3 if r > 0 => self.u - cols,
+                _ => continue,
+            };
+            let (from, to) = if self.incoming { (v, self.u) } else { (self.u, v) };
+            return Some((v, edge_weight(seed, from, to, max_weight)));
+        }
+        None
+    }
+}
+
+impl WeightedGraph for GridGraph {
+    type Neighbors<'a> = GridNeighbors<'a>;
+
+    fn vertex_count(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    fn out_neighbors(&self, u: usize) -> GridNeighbors<'_> {
+        self.neighbors(u, false)
+    }
+
+    fn edge_count(&self) -> usize {
+        2 * (self.rows * self.cols.saturating_sub(1) + self.cols * self.rows.saturating_sub(1))
+    }
+
+    fn in_neighbors(&self, v: usize) -> Option<GridNeighbors<'_>> {
+        Some(self.neighbors(v, true))
+    }
+}
+
+/// Complete directed graph on `n` vertices with hashed weights, for dense
+/// inputs without the n² edge storage
+#[derive(Debug, Clone, Copy)]
+pub struct CompleteGraph {
+    pub n: usize,
+    pub max_weight: f64,
+    pub seed: u64,
+}
+
+impl CompleteGraph {
+    pub fn new(n: usize, max_weight: f64, seed: u64) -> Self {
+        CompleteGraph { n, max_weight, seed }
+    }
+
+    fn neighbors(&self, u: usize, incoming: bool) -> CompleteNeighbors<'_> {
+        CompleteNeighbors { graph: self, u, next: 0, incoming }
+    }
+}
+
+/// Edges of a `CompleteGraph` vertex as (neighbour, weight) pairs
+pub struct CompleteNeighbors<'a> {
+    graph: &'a CompleteGraph,
+    u: usize,
+    next: usize,
+    incoming: bool,
+}
+
+impl Iterator for CompleteNeighbors<'_> {
+    type Item = (usize, f64);
+
+    fn next(&mut self) -> Option<(usize, f64)> {
+        if self.next == self.u {
+            self.next += 1;
+        }
+        if self.next >= self.graph.n {
+            return None;
+        }
+        let v = self.next;
+        self.next += 1;
+        let (from, to) = if self.incoming { (v, self.u) } else { (self.u, v) };
+        Some((v, edge_weight(self.graph.seed, from, to, self.graph.max_weight)))
+    }
+}
+
+impl WeightedGraph for CompleteGraph {
+    type Neighbors<'a> = CompleteNeighbors<'a>;
+
+    fn vertex_count(&self) -> usize {
+        self.n
+    }
+
+    fn out_neighbors(&self, u: usize) -> CompleteNeighbors<'_> {
+        self.neighbors(u, false)
+    }
+
+    fn edge_count(&self) -> usize {
+        self.n * self.n.saturating_sub(1)
+    }
+
+    fn out_degree(&self, _u: usize) -> usize {
+        self.n.saturating_sub(1)
+    }
+
+    fn in_neighbors(&self, v: usize) -> Option<CompleteNeighbors<'_>> {
+        Some(self.neighbors(v, true))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::graph::Graph;
+use crate::graph::WeightedGraph;
 use std::collections::BinaryHeap;
 use std::cmp::Ordering;
 
@@ -18,18 +18,18 @@ impl Eq for Node {}
 
 impl PartialOrd for Node {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.dist.partial_cmp(&self.dist)
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Node {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+        other.dist.partial_cmp(&self.dist).unwrap_or(Ordering::Equal)
     }
 }
 
-pub fn improved_sssp(graph: &Graph, source: usize) -> Vec<f64> {
-    let n = graph.n;
+pub fn improved_sssp<G: WeightedGraph>(graph: &G, source: usize) -> Vec<f64> {
+    let n = graph.vertex_count();
     
     let mut dist = vec![f64::INFINITY; n];
     let mut heap = BinaryHeap::new();
@@ -42,12 +42,12 @@ pub fn improved_sssp(graph: &Graph, source: usize) -> Vec<f64> {
             continue;
         }
         
-        for edge in &graph.edges[u] {
-            let new_dist = dist[u] + edge.weight;
+        for (to, weight) in graph.out_neighbors(u) {
+            let new_dist = dist[u] + weight;
             
-            if new_dist < dist[edge.to] {
-                dist[edge.to] = new_dist;
-                heap.push(Node { id: edge.to, dist: new_dist });
+            if new_dist < dist[to] {
+                dist[to] = new_dist;
+                heap.push(Node { id: to, dist: new_dist });
             }
         }
     }
@@ -1,4 +1,5 @@
-use crate::graph::Graph;
+use crate::dijkstra::OperationCounts;
+use crate::graph::WeightedGraph;
 use std::collections::{BinaryHeap, VecDeque};
 use std::cmp::Ordering;
 
@@ -18,18 +19,24 @@ impl Eq for Node {}
 
 impl PartialOrd for Node {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.dist.partial_cmp(&self.dist)
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Node {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+        other.dist.partial_cmp(&self.dist).unwrap_or(Ordering::Equal)
     }
 }
 
-pub fn improved_sssp_v2(graph: &Graph, source: usize) -> Vec<f64> {
-    let n = graph.n;
+pub fn improved_sssp_v2<G: WeightedGraph>(graph: &G, source: usize) -> Vec<f64> {
+    improved_sssp_v2_with_counts(graph, source).0
+}
+
+/// `improved_sssp_v2` that also returns its operation counts
+pub fn improved_sssp_v2_with_counts<G: WeightedGraph>(graph: &G, source: usize) -> (Vec<f64>, OperationCounts) {
+    let n = graph.vertex_count();
+    let mut counts = OperationCounts::default();
     let mut dist = vec![f64::INFINITY; n];
     dist[source] = 0.0;
     
@@ -37,27 +44,30 @@ pub fn improved_sssp_v2(graph: &Graph, source: usize) -> Vec<f64> {
     
     let mut frontier = BinaryHeap::new();
     frontier.push(Node { id: source, dist: 0.0 });
+    counts.heap_pushes += 1;
     
     let mut processed = vec![false; n];
     
     while !frontier.is_empty() {
         if frontier.len() <= threshold {
-            dijkstra_phase(&graph, &mut dist, &mut frontier, &mut processed);
+            dijkstra_phase(graph, &mut dist, &mut frontier, &mut processed, &mut counts);
         } else {
-            bellman_ford_phase(&graph, &mut dist, &mut frontier, &mut processed, threshold);
+            bellman_ford_phase(graph, &mut dist, &mut frontier, &mut processed, threshold, &mut counts);
         }
     }
     
-    dist
+    (dist, counts)
 }
 
-fn dijkstra_phase(
-    graph: &Graph,
-    dist: &mut Vec<f64>,
+fn dijkstra_phase<G: WeightedGraph>(
+    graph: &G,
+    dist: &mut [f64],
     frontier: &mut BinaryHeap<Node>,
-    processed: &mut Vec<bool>,
+    processed: &mut [bool],
+    counts: &mut OperationCounts,
 ) {
     while let Some(Node { id: u, dist: d }) = frontier.pop() {
+        counts.heap_pops += 1;
         if processed[u] {
             continue;
         }
@@ -67,22 +77,25 @@ fn dijkstra_phase(
             continue;
         }
         
-        for edge in &graph.edges[u] {
-            let new_dist = dist[u] + edge.weight;
-            if new_dist < dist[edge.to] {
-                dist[edge.to] = new_dist;
-                frontier.push(Node { id: edge.to, dist: new_dist });
+        for (to, weight) in graph.out_neighbors(u) {
+            counts.relaxations += 1;
+            let new_dist = dist[u] + weight;
+            if new_dist < dist[to] {
+                dist[to] = new_dist;
+                frontier.push(Node { id: to, dist: new_dist });
+                counts.heap_pushes += 1;
             }
         }
     }
 }
 
-fn bellman_ford_phase(
-    graph: &Graph,
-    dist: &mut Vec<f64>,
+fn bellman_ford_phase<G: WeightedGraph>(
+    graph: &G,
+    dist: &mut [f64],
     frontier: &mut BinaryHeap<Node>,
-    processed: &mut Vec<bool>,
+    processed: &mut [bool],
     threshold: usize,
+    counts: &mut OperationCounts,
 ) {
     let mut pivots = Vec::new();
     let mut temp_frontier = BinaryHeap::new();
@@ -91,9 +104,11 @@ fn bellman_ford_phase(
     
     for _ in 0..k.min(frontier.len()) {
         if let Some(node) = frontier.pop() {
+            counts.heap_pops += 1;
             if !processed[node.id] {
                 pivots.push(node.id);
                 temp_frontier.push(node);
+                counts.heap_pushes += 1;
             }
         }
     }
@@ -114,13 +129,15 @@ fn bellman_ford_phase(
                     continue;
                 }
                 
-                for edge in &graph.edges[u] {
-                    let new_dist = dist[u] + edge.weight;
-                    if new_dist < dist[edge.to] {
-                        dist[edge.to] = new_dist;
-                        queue.push_back(edge.to);
-                        if !processed[edge.to] {
-                            temp_frontier.push(Node { id: edge.to, dist: new_dist });
+                for (to, weight) in graph.out_neighbors(u) {
+                    counts.relaxations += 1;
+                    let new_dist = dist[u] + weight;
+                    if new_dist < dist[to] {
+                        dist[to] = new_dist;
+                        queue.push_back(to);
+                        if !processed[to] {
+                            temp_frontier.push(Node { id: to, dist: new_dist });
+                            counts.heap_pushes += 1;
                         }
                     }
                 }
@@ -134,14 +151,18 @@ fn bellman_ford_phase(
     }
     
     while let Some(node) = temp_frontier.pop() {
+        counts.heap_pops += 1;
         if frontier.len() < threshold * 2 {
             frontier.push(node);
+            counts.heap_pushes += 1;
         }
     }
     
     while let Some(node) = frontier.pop() {
+        counts.heap_pops += 1;
         if temp_frontier.len() < threshold * 2 {
             temp_frontier.push(node);
+            counts.heap_pushes += 1;
         } else {
             break;
         }
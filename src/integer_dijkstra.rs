@@ -0,0 +1,98 @@
+//! Dijkstra over the integer `MonotoneQueue`s. This is a separate core
+//! from `dijkstra::dijkstra` rather than a generic parameter on it: the
+//! bucket queues key on `u64` distances and need the largest edge weight up
+//! front, so they run on an `IntegerGraph` whose weights were quantized
+//! once, while `dijkstra` keeps exact `f64` distances on any
+//! `WeightedGraph`.
+
+use crate::graph::WeightedGraph;
+use crate::monotone_queue::{DialQueue, MonotoneQueue, RadixHeap, MAX_DIAL_STEP};
+
+/// Graph with non-negative integer weights for the bucket-based queues.
+/// Real weights are mapped to `round(weight * scale)`; distances are mapped
+/// back by dividing by `scale`.
+#[derive(Debug, Clone)]
+pub struct IntegerGraph {
+    pub n: usize,
+    pub edges: Vec<Vec<(usize, u64)>>,
+    pub scale: f64,
+    pub max_weight: u64,
+}
+
+impl IntegerGraph {
+    /// Rounds every weight after multiplying by `scale`. Each edge is off by
+    /// at most 0.5 / scale, so a path of h edges by at most h * 0.5 / scale.
+    pub fn quantize<G: WeightedGraph>(graph: &G, scale: f64) -> Self {
+        assert!(scale > 0.0, "scale must be positive");
+        let n = graph.vertex_count();
+        let mut max_weight = 0;
+        let edges = (0..n)
+            .map(|u| {
+                graph
+                    .out_neighbors(u)
+                    .map(|(to, weight)| {
+                        assert!(weight >= 0.0, "integer queues need non-negative weights");
+                        let w = (weight * scale).round() as u64;
+                        max_weight = max_weight.max(w);
+                        (to, w)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        IntegerGraph { n, edges, scale, max_weight }
+    }
+
+    /// Converts without loss, or returns `None` if some weight is negative
+    /// or not an integer
+    pub fn exact<G: WeightedGraph>(graph: &G) -> Option<Self> {
+        let integral = (0..graph.vertex_count())
+            .flat_map(|u| graph.out_neighbors(u))
+            .all(|(_, weight)| weight >= 0.0 && weight.fract() == 0.0);
+        integral.then(|| IntegerGraph::quantize(graph, 1.0))
+    }
+}
+
+/// Dijkstra over any monotone queue, with lazy deletion as in `dijkstra`.
+/// Distances saturate at `u64::MAX`, which means unreachable, so a vertex
+/// only reachable by a path longer than that comes out as infinity.
+pub fn dijkstra_with_queue<Q: MonotoneQueue>(graph: &IntegerGraph, source: usize) -> Vec<f64> {
+    let n = graph.n;
+    let mut dist = vec![u64::MAX; n];
+    let mut queue = Q::with_max_step(graph.max_weight);
+
+    dist[source] = 0;
+    queue.push(0, source);
+
+    while let Some((d, u)) = queue.pop() {
+        if d > dist[u] {
+            continue;
+        }
+
+        for &(v, w) in &graph.edges[u] {
+            let new_dist = d.saturating_add(w);
+
+            if new_dist < dist[v] {
+                dist[v] = new_dist;
+                queue.push(new_dist, v);
+            }
+        }
+    }
+
+    dist.into_iter()
+        .map(|d| if d == u64::MAX { f64::INFINITY } else { d as f64 / graph.scale })
+        .collect()
+}
+
+pub fn radix_heap_dijkstra(graph: &IntegerGraph, source: usize) -> Vec<f64> {
+    dijkstra_with_queue::<RadixHeap>(graph, source)
+}
+
+/// Dijkstra with Dial's buckets, or with the radix heap when the largest
+/// weight exceeds `MAX_DIAL_STEP` and the buckets would not fit
+pub fn dial_dijkstra(graph: &IntegerGraph, source: usize) -> Vec<f64> {
+    if graph.max_weight > MAX_DIAL_STEP {
+        return radix_heap_dijkstra(graph, source);
+    }
+    dijkstra_with_queue::<DialQueue>(graph, source)
+}
@@ -0,0 +1,85 @@
+use crate::graph::{Graph, WeightedGraph};
+use crate::bellman_ford::{potentials, NegativeCycle};
+use crate::solver::Solver;
+use rayon::prelude::*;
+use std::io::{self, Write};
+
+/// Johnson's all-pairs shortest paths. Bellman-Ford potentials make every
+/// weight non-negative, then any `Solver` is run from each vertex on the
+/// reweighted graph and the potentials are undone per row.
+pub struct Johnson {
+    pub solver: Solver,
+    reweighted: Graph,
+    potentials: Vec<f64>,
+}
+
+impl Johnson {
+    /// Reweights the graph, failing if it contains a negative cycle. Graphs
+    /// without negative weights skip Bellman-Ford (all potentials are 0).
+    pub fn new<G: WeightedGraph>(graph: &G, solver: Solver) -> Result<Self, NegativeCycle> {
+        let n = graph.vertex_count();
+        let potentials = if graph.has_negative_weights() {
+            potentials(graph)?
+        } else {
+            vec![0.0; n]
+        };
+
+        let mut reweighted = Graph::new(n);
+        for u in 0..n {
+            for (to, weight) in graph.out_neighbors(u) {
+                // Clamp rounding noise so the result is truly non-negative
+                let weight = (weight + potentials[u] - potentials[to]).max(0.0);
+                reweighted.add_edge(u, to, weight);
+            }
+        }
+        reweighted.coords = graph.coords().map(<[_]>::to_vec);
+
+        Ok(Johnson { solver, reweighted, potentials })
+    }
+
+    /// Distances from `source` in the original weights
+    pub fn row(&self, source: usize) -> Vec<f64> {
+        let h_source = self.potentials[source];
+        self.solver
+            .run(&self.reweighted, source)
+            .into_iter()
+            .zip(&self.potentials)
+            .map(|(d, &h)| d - h_source + h)
+            .collect()
+    }
+
+    /// Dense n x n distance matrix, for small graphs. With `parallel`, rows
+    /// are computed on the current rayon pool.
+    pub fn matrix(&self, parallel: bool) -> Vec<Vec<f64>> {
+        let n = self.reweighted.n;
+        if parallel {
+            (0..n).into_par_iter().map(|s| self.row(s)).collect()
+        } else {
+            (0..n).map(|s| self.row(s)).collect()
+        }
+    }
+
+    /// Writes one line per source (space-separated distances, `inf` when
+    /// unreachable) without holding the whole matrix. With `parallel`, rows
+    /// are computed in batches and still written in source order.
+    pub fn write_rows<W: Write>(&self, out: &mut W, parallel: bool) -> io::Result<()> {
+        let n = self.reweighted.n;
+        let batch = if parallel { rayon::current_num_threads() * 4 } else { 1 };
+
+        for start in (0..n).step_by(batch.max(1)) {
+            let end = (start + batch).min(n);
+            let rows: Vec<Vec<f64>> = if parallel {
+                (start..end).into_par_iter().map(|s| self.row(s)).collect()
+            } else {
+                (start..end).map(|s| self.row(s)).collect()
+            };
+
+            for row in rows {
+                let line: Vec<String> = row.iter().map(|d| d.to_string()).collect();
+                writeln!(out, "{}", line.join(" "))?;
+            }
+        }
+
+        out.flush()
+    }
+}
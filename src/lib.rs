@@ -2,4 +2,34 @@ pub mod graph;
 pub mod dijkstra;
 pub mod improved_sssp;
 pub mod improved_sssp_v2;
-pub mod core_algorithm;
\ No newline at end of file
+pub mod core_algorithm;
+pub mod bidirectional;
+pub mod dimacs;
+pub mod astar;
+pub mod alt;
+pub mod contraction_hierarchy;
+pub mod delta_stepping;
+pub mod csr;
+pub mod parallel;
+pub mod parallel_core;
+pub mod monotone_queue;
+pub mod integer_dijkstra;
+pub mod addressable_heap;
+pub mod decrease_key_dijkstra;
+pub mod bellman_ford;
+pub mod dispatch;
+pub mod solver;
+pub mod johnson;
+pub mod floyd_warshall;
+pub mod dag;
+pub mod scc;
+pub mod reachability;
+pub mod cli;
+pub mod report;
+pub mod stats;
+pub mod regression;
+pub mod baseline;
+pub mod counting_allocator;
+pub mod workspace;
+pub mod mmap_graph;
+pub mod implicit;
@@ -1,77 +1,564 @@
-mod graph;
-mod dijkstra;
-mod improved_sssp;
-mod improved_sssp_v2;
-mod core_algorithm;
-
-use graph::Graph;
-use dijkstra::dijkstra;
-use improved_sssp::improved_sssp;
-use improved_sssp_v2::improved_sssp_v2;
-use core_algorithm::CoreAlgorithm;
+use shortest_path_validation::graph::{Graph, WeightedGraph};
+use shortest_path_validation::cli::{max_difference, open_renderer, parse_solvers, select_sources, GraphSpec, Options};
+use shortest_path_validation::dimacs::{write_coordinates, write_graph};
+use shortest_path_validation::mmap_graph::write_binary;
+use shortest_path_validation::astar::{astar, warn_if_inadmissible, EuclideanHeuristic};
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::dispatch::shortest_paths;
+use shortest_path_validation::floyd_warshall::FloydWarshall;
+use shortest_path_validation::reachability::{pruned_sssp, ReachableSubgraph};
+use shortest_path_validation::baseline::Comparison;
+use shortest_path_validation::counting_allocator;
+use shortest_path_validation::report::{parse_csv, renderer, Format, Record};
+use shortest_path_validation::scc::tarjan_scc;
+use shortest_path_validation::solver::Solver;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::process::ExitCode;
 use std::time::Instant;
 
-fn main() {
-    println!("Shortest Path Algorithm Validation\n");
-    println!("Testing on various graph sizes:");
-    println!("{:<10} {:<10} {:<15} {:<15} {:<15} {:<15} {:<10}", 
-             "Nodes", "Edges", "Dijkstra (ms)", "Improved (ms)", "ImprovedV2 (ms)", "Core (ms)", "Best Speedup");
-    println!("{}", "-".repeat(95));
-    
-    let densities = vec![0.05, 0.1];
-    let sizes = vec![100, 500, 1000, 2000, 5000];
-    
-    for n in sizes {
-        for &density in &densities {
-            let graph = Graph::generate_random(n, density, 100.0);
-            let m = graph.m();
-            
-            let start = Instant::now();
-            let dist1 = dijkstra(&graph, 0);
-            let dijkstra_time = start.elapsed().as_secs_f64() * 1000.0;
-            
-            let start = Instant::now();
-            let dist2 = improved_sssp(&graph, 0);
-            let improved_time = start.elapsed().as_secs_f64() * 1000.0;
-            
-            let start = Instant::now();
-            let dist3 = improved_sssp_v2(&graph, 0);
-            let improved_v2_time = start.elapsed().as_secs_f64() * 1000.0;
-            
-            let start = Instant::now();
-            let core_algo = CoreAlgorithm::new(graph.clone());
-            let dist4 = core_algo.sssp(0);
-            let core_time = start.elapsed().as_secs_f64() * 1000.0;
-            
-            let best_improved = improved_time.min(improved_v2_time).min(core_time);
-            let speedup = dijkstra_time / best_improved;
-            
-            println!("{:<10} {:<10} {:<15.3} {:<15.3} {:<15.3} {:<15.3} {:<10.2}x", 
-                     n, m, dijkstra_time, improved_time, improved_v2_time, core_time, speedup);
-            
-            let mut max_diff = 0.0f64;
-            for i in 0..n {
-                if dist1[i].is_finite() && dist2[i].is_finite() {
-                    max_diff = max_diff.max((dist1[i] - dist2[i]).abs());
+mod analysis;
+
+const USAGE: &str = "\
+Usage: shortest-path-validation <command> [options]
+
+Commands:
+  generate  Write a generated graph as DIMACS files
+  solve     Run one solver and print per-source summaries
+  compare   Time solvers side by side and check them against a reference
+  bench     Time solvers over repeated runs
+  verify    Check solvers against an oracle, exit 1 on any mismatch
+  info      Print graph statistics
+  analyze   Timing tables, complexity fits, parallel scaling and SCC structure
+
+Graphs (--graph, repeatable for compare/bench/verify):
+  random:n=1000,density=0.05[,max_weight=100]
+  geometric:n=1000,radius=0.05
+  grid:rows=100,cols=100[,max_weight=100]
+  scale-free:n=1000,attach=3[,max_weight=100]
+  file:path=graph.gr[,co=graph.co]   or just graph.gr (graph.bin: binary CSR)
+
+Common options:
+  --seed S               Seed for generated graphs and random sources (default 1)
+  --source V[,V...]      Source vertices (repeatable)
+  --random-sources K     K random distinct sources when --source is not given
+                         (default 5 for compare/bench, else vertex 0)
+  --solver/--solvers     Solver names, or `all`; `solve` also accepts `auto`
+  --reps R               Timed repetitions per source (compare 3, bench 5)
+  --warmup W             Untimed runs per source before timing (default 1)
+  --format F             compare/bench output: table (default), json (lines) or csv
+  --output FILE          Write compare/bench output to FILE instead of stdout
+  --prune                solve/compare/bench: search only the subgraph reachable
+                         from each source (pruning counts as setup) and report
+                         how much was pruned
+
+Command options:
+  generate  [--out FILE.gr] [--coords-out FILE.co] [--binary-out FILE.bin]
+  solve     [--out FILE]   writes `source vertex distance` lines
+  bench     [--save-baseline FILE]   store this run (CSV records)
+            [--baseline FILE] [--threshold PCT]   compare medians per graph and
+            solver against a stored run, exit 1 if any is more than PCT
+            percent slower (default 10); use the same --graph and --seed
+  verify    [--tolerance T] [--oracle-limit N]   Floyd-Warshall up to N vertices;
+            graphs with coordinates also check A*, warning if its heuristic
+            is inadmissible
+  analyze   [--sizes N,...] [--densities D,...]   one random-graph table per
+            density (default 100..5000 and 0.01,0.05,0.2); --graph replaces them
+            [--scaling-sizes N,...] [--scaling-density D]   complexity fits
+            [--threads T,...] [--parallel-sizes N,...]   parallel scaling
+            [--scc-n N] [--scc-densities D,...]   component structure
+            sources, reps and warm-up default to 3, 3 and 1
+
+Solvers: ";
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: counting_allocator::CountingAllocator = counting_allocator::CountingAllocator;
+
+const DEFAULT_COMPARE: &str = "dijkstra,improved,improved-v2,core,delta-stepping";
+
+/// Random sources per graph for compare/bench when --source is not given
+const DEFAULT_SOURCES: usize = 5;
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let Some(command) = args.next() else {
+        eprintln!("{}", usage());
+        return ExitCode::from(2);
+    };
+
+    let result = Options::parse(args, &["prune"]).and_then(|options| match command.as_str() {
+        "generate" => generate(&options),
+        "solve" => solve(&options),
+        "compare" => compare(&options),
+        "bench" => bench(&options),
+        "verify" => verify(&options),
+        "info" => info(&options),
+        "analyze" => analysis::analyze(&options),
+        "help" | "--help" | "-h" => {
+            println!("{}", usage());
+            Ok(true)
+        }
+        _ => Err(format!("unknown command '{}'", command)),
+    });
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            eprintln!("Run `shortest-path-validation help` for usage.");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn usage() -> String {
+    let names: Vec<&str> = Solver::ALL.iter().map(|s| s.name()).collect();
+    format!("{}{}", USAGE, names.join(", "))
+}
+
+/// Graphs from every --graph option, built with --seed
+fn load_graphs(options: &Options) -> Result<Vec<(GraphSpec, Graph)>, String> {
+    let specs = options.get_all("graph");
+    if specs.is_empty() {
+        return Err("no graph given, use --graph".to_string());
+    }
+
+    let seed = options.parse_or("seed", 1u64)?;
+    specs
+        .into_iter()
+        .map(|spec| {
+            let spec: GraphSpec = spec.parse()?;
+            let graph = spec.build(seed)?;
+            Ok((spec, graph))
+        })
+        .collect()
+}
+
+fn load_graph(options: &Options) -> Result<(GraphSpec, Graph), String> {
+    let mut graphs = load_graphs(options)?;
+    if graphs.len() > 1 {
+        return Err("this command takes a single --graph".to_string());
+    }
+    Ok(graphs.remove(0))
+}
+
+/// Sources from --source, else --random-sources (`default_random` if not
+/// given, 0 meaning vertex 0 only)
+fn sources(options: &Options, graph: &Graph, default_random: usize) -> Result<Vec<usize>, String> {
+    let explicit: Vec<usize> = options.parse_list("source")?;
+    let random = options.parse_or("random-sources", default_random)?;
+    let seed = options.parse_or("seed", 1u64)?;
+    select_sources(&explicit, random, graph.n, seed)
+}
+
+fn solvers(options: &Options, default: &str) -> Result<Vec<Solver>, String> {
+    let mut names = options.get_list("solvers");
+    names.extend(options.get_list("solver"));
+    if names.is_empty() {
+        names = default.split(',').collect();
+    }
+    parse_solvers(&names)
+}
+
+/// Runs `f` and returns its result with the elapsed time in milliseconds
+/// Sets up `solver` and solves from `source`, returning the distances with
+/// the setup and solve times. With `prune` the solver runs on the subgraph
+/// reachable from `source`, and building it counts as setup.
+fn timed_run(graph: &Graph, solver: Solver, source: usize, prune: bool) -> (Vec<f64>, f64, f64) {
+    if prune {
+        let (reachable, prune_ms) = timed(|| ReachableSubgraph::build(graph, source));
+        let (instance, setup) = timed(|| solver.setup(&reachable.graph));
+        let (dist, time) = timed(|| instance.solve(0));
+        (reachable.expand(&dist), prune_ms + setup, time)
+    } else {
+        let (instance, setup) = timed(|| solver.setup(graph));
+        let (dist, time) = timed(|| instance.solve(source));
+        (dist, setup, time)
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, f64) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed().as_secs_f64() * 1000.0)
+}
+
+fn generate(options: &Options) -> Result<bool, String> {
+    options.check_known(&["graph", "seed", "out", "coords-out", "binary-out"])?;
+    let (spec, graph) = load_graph(options)?;
+    if options.get("out").is_none() && options.get("binary-out").is_none() {
+        return Err("generate needs --out or --binary-out".to_string());
+    }
+
+    if let Some(out) = options.get("out") {
+        write_graph(&graph, out).map_err(|e| format!("{}: {}", out, e))?;
+        println!("Wrote {} ({} vertices, {} edges) to {}", spec, graph.n, graph.m(), out);
+    }
+    if let Some(bin) = options.get("binary-out") {
+        write_binary(&graph, bin).map_err(|e| format!("{}: {}", bin, e))?;
+        println!("Wrote {} ({} vertices, {} edges) to {}", spec, graph.n, graph.m(), bin);
+    }
+
+    if let Some(co) = options.get("coords-out") {
+        let coords = graph.coords.as_ref().ok_or("this graph family has no coordinates")?;
+        write_coordinates(coords, co).map_err(|e| format!("{}: {}", co, e))?;
+        println!("Wrote coordinates to {}", co);
+    }
+    Ok(true)
+}
+
+fn solve(options: &Options) -> Result<bool, String> {
+    options.check_known(&["graph", "seed", "solver", "source", "random-sources", "out", "prune"])?;
+    let (spec, graph) = load_graph(options)?;
+    let sources = sources(options, &graph, 0)?;
+    let prune = options.flag("prune");
+
+    let name = options.get("solver").unwrap_or("auto");
+    let solver = match name {
+        "auto" => None,
+        _ => Some(Solver::from_name(name).ok_or_else(|| format!("unknown solver '{}'", name))?),
+    };
+    if solver.is_some() && graph.has_negative_weights() {
+        return Err(format!("{} needs non-negative weights, use --solver auto", name));
+    }
+
+    let mut out = match options.get("out") {
+        Some(path) => Some(BufWriter::new(File::create(path).map_err(|e| format!("{}: {}", path, e))?)),
+        None => None,
+    };
+
+    println!("Graph: {} ({} vertices, {} edges)", spec, graph.n, graph.m());
+    println!("{:<10} {:<15} {:<12} {:<15} {:<15} {:<15}",
+             "Source", "Algorithm", "Reachable", "Max Dist", "Setup (ms)", "Time (ms)");
+    println!("{}", "-".repeat(85));
+
+    for s in sources {
+        let (algorithm, dist, setup, time) = match solver {
+            Some(solver) => {
+                let (dist, setup, time) = timed_run(&graph, solver, s, prune);
+                (solver.name(), dist, setup, time)
+            }
+            None if prune => {
+                let (reachable, setup) = timed(|| ReachableSubgraph::build(&graph, s));
+                let (solution, time) = timed(|| shortest_paths(&reachable.graph, 0));
+                let solution = solution.map_err(|cycle| format!("from source {}: {}", s, cycle))?;
+                (solution.algorithm.name(), reachable.expand(&solution.dist), setup, time)
+            }
+            None => {
+                let (solution, time) = timed(|| shortest_paths(&graph, s));
+                let solution = solution.map_err(|cycle| format!("from source {}: {}", s, cycle))?;
+                (solution.algorithm.name(), solution.dist, 0.0, time)
+            }
+        };
+
+        let reachable = dist.iter().filter(|d| d.is_finite()).count();
+        let max_dist = dist.iter().copied().filter(|d| d.is_finite()).fold(0.0, f64::max);
+        println!("{:<10} {:<15} {:<12} {:<15.3} {:<15.3} {:<15.3}",
+                 s, algorithm, reachable, max_dist, setup, time);
+        if prune {
+            println!("{:<10} pruned: {}", "", ReachableSubgraph::build(&graph, s).stats);
+        }
+
+        if let Some(out) = out.as_mut() {
+            for (v, d) in dist.iter().enumerate() {
+                writeln!(out, "{} {} {}", s, v, d).map_err(|e| e.to_string())?;
+            }
+        }
+    }
+
+    if let Some(mut out) = out {
+        out.flush().map_err(|e| e.to_string())?;
+    }
+    Ok(true)
+}
+
+fn compare(options: &Options) -> Result<bool, String> {
+    options.check_known(&["graph", "seed", "solver", "solvers", "source", "random-sources", "reps", "warmup",
+                          "format", "output", "prune"])?;
+    let solvers = solvers(options, DEFAULT_COMPARE)?;
+    let reps = options.parse_or("reps", 3usize)?.max(1);
+    let warmup = options.parse_or("warmup", 1usize)?;
+    run_measurements(options, &solvers, reps, warmup, true).map(|_| true)
+}
+
+fn bench(options: &Options) -> Result<bool, String> {
+    options.check_known(&["graph", "seed", "solver", "solvers", "source", "random-sources", "reps", "warmup",
+                          "format", "output", "prune", "save-baseline", "baseline", "threshold"])?;
+    let solvers = solvers(options, "all")?;
+    let reps = options.parse_or("reps", 5usize)?.max(1);
+    let warmup = options.parse_or("warmup", 1usize)?;
+    let threshold = options.parse_or("threshold", 10.0)? / 100.0;
+    // Read the baseline first so a bad path fails before the measurements
+    let baseline = match options.get("baseline") {
+        Some(path) => {
+            let file = File::open(path).map_err(|e| format!("{}: {}", path, e))?;
+            Some(parse_csv(BufReader::new(file)).map_err(|e| format!("{}: {}", path, e))?)
+        }
+        None => None,
+    };
+
+    let records = run_measurements(options, &solvers, reps, warmup, false)?;
+
+    if let Some(path) = options.get("save-baseline") {
+        let file = File::create(path).map_err(|e| format!("{}: {}", path, e))?;
+        let mut out = renderer(Format::Csv, BufWriter::new(file));
+        for record in &records {
+            out.record(record).map_err(|e| e.to_string())?;
+        }
+        out.finish().map_err(|e| e.to_string())?;
+        eprintln!("Saved baseline with {} runs to {}", records.len(), path);
+    }
+
+    match baseline {
+        Some(baseline) => report_baseline(options, &Comparison::new(&baseline, &records), threshold),
+        None => Ok(true),
+    }
+}
+
+/// Prints each (graph, solver) median against the baseline and returns
+/// false when any is slower by more than `threshold`. Goes to stderr when
+/// json/csv records are streaming to stdout.
+fn report_baseline(options: &Options, comparison: &Comparison, threshold: f64) -> Result<bool, String> {
+    let records_on_stdout = options.get("output").is_none() && options.get("format").is_some_and(|f| f != "table");
+    let mut out: Box<dyn Write> = if records_on_stdout { Box::new(io::stderr()) } else { Box::new(io::stdout()) };
+    let io_error = |e: io::Error| e.to_string();
+
+    writeln!(out, "\nBaseline comparison (regression threshold +{:.0}%)", threshold * 100.0).map_err(io_error)?;
+    let mut graph = None;
+    for change in &comparison.changes {
+        if graph != Some(&change.graph) {
+            writeln!(out, "\nGraph: {}", change.graph).map_err(io_error)?;
+            writeln!(out, "{:<25} {:<15} {:<15} {:<10}", "Solver", "Baseline (ms)", "Current (ms)", "Change").map_err(io_error)?;
+            writeln!(out, "{}", "-".repeat(68)).map_err(io_error)?;
+            graph = Some(&change.graph);
+        }
+        writeln!(out, "{:<25} {:<15.3} {:<15.3} {:<10}", change.solver, change.baseline_ms, change.current_ms,
+                 format!("{:+.1}%", change.slowdown() * 100.0)).map_err(io_error)?;
+    }
+
+    for (graph, solver) in &comparison.missing {
+        writeln!(out, "NOTE: {} on {} is in the baseline but was not run", solver, graph).map_err(io_error)?;
+    }
+    for (graph, solver) in &comparison.added {
+        writeln!(out, "NOTE: {} on {} has no baseline", solver, graph).map_err(io_error)?;
+    }
+    let regressions = comparison.regressions(threshold);
+    for change in &regressions {
+        writeln!(out, "REGRESSION: {} on {} is {:.1}% slower ({:.3} ms -> {:.3} ms)", change.solver, change.graph,
+                 change.slowdown() * 100.0, change.baseline_ms, change.current_ms).map_err(io_error)?;
+    }
+    if comparison.changes.is_empty() {
+        writeln!(out, "WARNING: no graph and solver pair matches the baseline").map_err(io_error)?;
+    }
+
+    Ok(regressions.is_empty())
+}
+
+/// Runs every solver from every source on every graph: `warmup` untimed
+/// runs, then `reps` timed runs, each emitted as one record with setup and
+/// solve timed separately. Sources default to `DEFAULT_SOURCES` random
+/// vertices. With `check`, every run is compared against Dijkstra's
+/// distances. Built with the `count-allocations` feature, records also
+/// carry the allocations, bytes and peak heap of setup plus solve. With
+/// `--prune`, runs search only the subgraph reachable from their source,
+/// the pruning is printed once per source on stderr and records carry
+/// `pruned_vertices` and `pruned_edges`. Returns the records for further
+/// comparison.
+fn run_measurements(options: &Options, solvers: &[Solver], reps: usize, warmup: usize, check: bool) -> Result<Vec<Record>, String> {
+    let graphs = load_graphs(options)?;
+    let mut out = open_renderer(options)?;
+    let io_error = |e: io::Error| e.to_string();
+    let mut records = Vec::new();
+    let count_allocations = counting_allocator::is_installed();
+    let prune = options.flag("prune");
+
+    for (spec, graph) in &graphs {
+        if graph.has_negative_weights() {
+            eprintln!("Skipping {}: registry solvers need non-negative weights", spec);
+            continue;
+        }
+        let sources = sources(options, graph, DEFAULT_SOURCES)?;
+        let graph_id = spec.to_string();
+
+        let pruning: Vec<_> = if prune {
+            sources.iter().map(|&s| ReachableSubgraph::build(graph, s).stats).collect()
+        } else {
+            Vec::new()
+        };
+        for (s, stats) in sources.iter().zip(&pruning) {
+            eprintln!("Pruning {} from source {}: {}", spec, s, stats);
+        }
+
+        let references: Vec<Vec<f64>> = if check {
+            sources.iter().map(|&s| dijkstra(graph, s)).collect()
+        } else {
+            Vec::new()
+        };
+
+        for &solver in solvers {
+            for (i, &s) in sources.iter().enumerate() {
+                for _ in 0..warmup {
+                    let _ = if prune { pruned_sssp(graph, s, solver).0 } else { solver.run(graph, s) };
+                }
+                for rep in 0..reps {
+                    let ((dist, setup, time), memory) = counting_allocator::measure(|| timed_run(graph, solver, s, prune));
+
+                    let mut record = Record::new(&graph_id, graph, solver.name(), s, time);
+                    record.rep = rep;
+                    record.setup_ms = setup;
+                    if let Some(expected) = references.get(i) {
+                        record.max_error = Some(max_difference(&dist, expected));
+                    }
+                    let reachable = dist.iter().filter(|d| d.is_finite()).count();
+                    record.counters.push(("reachable".to_string(), reachable as u64));
+                    if let Some(stats) = pruning.get(i) {
+                        record.counters.push(("pruned_vertices".to_string(), stats.pruned_vertices() as u64));
+                        record.counters.push(("pruned_edges".to_string(), stats.pruned_edges() as u64));
+                    }
+                    if count_allocations {
+                        record.counters.push(("allocations".to_string(), memory.allocations));
+                        record.counters.push(("alloc_bytes".to_string(), memory.allocated_bytes));
+                        record.counters.push(("peak_bytes".to_string(), memory.peak_bytes as u64));
+                    }
+                    out.record(&record).map_err(io_error)?;
+                    records.push(record);
+                }
+            }
+        }
+    }
+
+    out.finish().map_err(io_error)?;
+    Ok(records)
+}
+
+fn verify(options: &Options) -> Result<bool, String> {
+    options.check_known(&["graph", "seed", "solver", "solvers", "source", "random-sources", "tolerance", "oracle-limit"])?;
+    let graphs = load_graphs(options)?;
+    let solvers = solvers(options, "all")?;
+    let tolerance = options.parse_or("tolerance", 1e-9)?;
+    let oracle_limit = options.parse_or("oracle-limit", 300usize)?;
+    let mut all_ok = true;
+
+    for (spec, graph) in &graphs {
+        let sources = sources(options, graph, 0)?;
+        let negative_weights = graph.has_negative_weights();
+        let oracle = (graph.n <= oracle_limit).then(|| FloydWarshall::new(graph));
+        println!("\nGraph: {} ({} vertices, {} edges), oracle: {}",
+                 spec, graph.n, graph.m(), if oracle.is_some() { "floyd-warshall" } else { "auto" });
+
+        if oracle.as_ref().is_some_and(|fw| fw.has_negative_cycle()) {
+            println!("Graph has a negative cycle, nothing to verify");
+            continue;
+        }
+
+        let mut expected = Vec::new();
+        for &s in &sources {
+            let auto = shortest_paths(graph, s);
+            match (&oracle, auto) {
+                (Some(fw), Ok(solution)) => {
+                    let ok = report("auto", max_difference(&solution.dist, &fw.dist[s]), tolerance, s);
+                    all_ok &= ok;
+                    expected.push(fw.dist[s].clone());
                 }
-                if dist1[i].is_finite() && dist3[i].is_finite() {
-                    max_diff = max_diff.max((dist1[i] - dist3[i]).abs());
+                (Some(fw), Err(cycle)) => {
+                    // The oracle found no negative cycle
+                    println!("{:<25} FAIL  reported {} from source {}", "auto", cycle, s);
+                    all_ok = false;
+                    expected.push(fw.dist[s].clone());
                 }
-                if dist1[i].is_finite() && dist4[i].is_finite() {
-                    max_diff = max_diff.max((dist1[i] - dist4[i]).abs());
+                (None, Ok(solution)) => expected.push(solution.dist),
+                (None, Err(cycle)) => {
+                    println!("Negative cycle reachable from source {}: {}", s, cycle);
+                    return Ok(false);
                 }
             }
-            
-            if max_diff > 1e-9 {
-                println!("WARNING: Results differ by {:.2e}", max_diff);
+        }
+
+        for &solver in &solvers {
+            if negative_weights {
+                println!("{:<25} skipped (negative weights)", solver.name());
+                continue;
+            }
+            let mut ok = true;
+            for (&s, expected) in sources.iter().zip(&expected) {
+                ok &= report(solver.name(), max_difference(&solver.run(graph, s), expected), tolerance, s);
+            }
+            if ok {
+                println!("{:<25} OK", solver.name());
             }
+            all_ok &= ok;
         }
+
+        // A* with the coordinate heuristic, from each source to its farthest
+        // reachable vertex; warns on stderr if the heuristic is inadmissible
+        if let Some(heuristic) = EuclideanHeuristic::from_graph(graph).filter(|_| !negative_weights) {
+            let mut ok = true;
+            for (&s, expected) in sources.iter().zip(&expected) {
+                let target = (0..graph.n).filter(|&v| expected[v].is_finite()).max_by(|&a, &b| expected[a].total_cmp(&expected[b]));
+                let Some(target) = target else { continue };
+                warn_if_inadmissible(graph, &heuristic, target);
+                let diff = max_difference(&[astar(graph, s, target, &heuristic)], &[expected[target]]);
+                ok &= report("astar", diff, tolerance, s);
+            }
+            if ok {
+                println!("{:<25} OK", "astar");
+            }
+            all_ok &= ok;
+        }
+    }
+
+    println!("\n{}", if all_ok { "All solvers agree" } else { "FAILED" });
+    Ok(all_ok)
+}
+
+/// Prints a failure line when `diff` exceeds `tolerance`
+fn report(name: &str, diff: f64, tolerance: f64, source: usize) -> bool {
+    if diff > tolerance {
+        println!("{:<25} FAIL  max diff {:.2e} from source {}", name, diff, source);
+        false
+    } else {
+        true
+    }
+}
+
+fn info(options: &Options) -> Result<bool, String> {
+    options.check_known(&["graph", "seed", "source"])?;
+    let (spec, graph) = load_graph(options)?;
+    let n = graph.n;
+    let m = graph.m();
+    // An empty graph is still described; only explicit sources are checked.
+    // Resolved before printing so a bad --source fails without partial output
+    let sources = if n == 0 && options.get("source").is_none() { Vec::new() } else { sources(options, &graph, 0)? };
+
+    let degrees: Vec<usize> = graph.edges.iter().map(|e| e.len()).collect();
+    let weights = graph.edges.iter().flatten().map(|e| e.weight);
+    let min_weight = weights.clone().fold(f64::INFINITY, f64::min);
+    let max_weight = weights.fold(f64::NEG_INFINITY, f64::max);
+    let components = tarjan_scc(&graph);
+    let largest = components.sizes().into_iter().max().unwrap_or(0);
+
+    println!("{:<24} {}", "Graph", spec);
+    println!("{:<24} {}", "Vertices", n);
+    println!("{:<24} {}", "Edges", m);
+    if n > 1 {
+        println!("{:<24} {:.6}", "Density", m as f64 / (n as f64 * (n - 1) as f64));
+    }
+    if n > 0 {
+        println!("{:<24} {} / {:.2} / {}", "Out-degree min/avg/max",
+                 degrees.iter().min().unwrap_or(&0), m as f64 / n as f64, degrees.iter().max().unwrap_or(&0));
+    }
+    if m > 0 {
+        println!("{:<24} {} .. {}", "Weights", min_weight, max_weight);
+    }
+    println!("{:<24} {}", "Negative weights", graph.has_negative_weights());
+    println!("{:<24} {}", "Coordinates", graph.coords.is_some());
+    println!("{:<24} {}", "Acyclic", graph.is_acyclic());
+    println!("{:<24} {} (largest {})", "SCCs", components.count, largest);
+
+    for s in sources {
+        println!("{:<24} {}", format!("From source {}", s), ReachableSubgraph::build(&graph, s).stats);
     }
-    
-    println!("\nComplexity Analysis:");
-    println!("Dijkstra: O(m log n) = O(m + n log n)");
-    println!("Improved V1: Simple implementation");
-    println!("Improved V2: Dijkstra-Bellman-Ford hybrid with adaptive frontier management");
-    println!("Core Algorithm: Paper's exact implementation with BMSSP, FindPivots, and partial sorting");
-    println!("Target: O(m log^(2/3) n) [claimed in paper]");
+    Ok(true)
 }
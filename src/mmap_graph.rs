@@ -0,0 +1,167 @@
+use crate::graph::WeightedGraph;
+use memmap2::Mmap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// First bytes of a binary CSR file
+pub const MAGIC: &[u8; 8] = b"SSSPCSR1";
+
+const HEADER: usize = 24;
+
+/// Graph read in place from a binary CSR file, so graphs larger than
+/// memory can be searched without parsing them first. The file is
+///
+/// ```text
+/// magic "SSSPCSR1" | n: u64 | m: u64 | offsets: (n + 1) x u64 |
+/// targets: m x u64 | weights: m x f64
+/// ```
+///
+/// with every number little-endian; `write_binary` produces it. The file
+/// must not change while it is mapped.
+pub struct MmapGraph {
+    map: Mmap,
+    n: usize,
+    m: usize,
+}
+
+impl MmapGraph {
+    /// Maps `path` and checks its layout: size, monotone offsets ending at
+    /// m, and targets below n
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // Safety: the map is only read, and the format requires that the
+        // file is not modified while mapped
+        let map = unsafe { Mmap::map(&file)? };
+
+        if map.len() < HEADER || &map[..8] != MAGIC {
+            return Err(invalid("not a binary CSR graph".to_string()));
+        }
+        let n = read_u64(&map, 8) as usize;
+        let m = read_u64(&map, 16) as usize;
+        let expected = n
+            .checked_add(1)
+            .zip(m.checked_mul(2))
+            .and_then(|(offsets, edges)| offsets.checked_add(edges))
+            .and_then(|words| words.checked_mul(8))
+            .and_then(|bytes| bytes.checked_add(HEADER));
+        if expected != Some(map.len()) {
+            return Err(invalid(format!("file has {} bytes, expected {:?} for n={} m={}", map.len(), expected, n, m)));
+        }
+
+        let graph = MmapGraph { map, n, m };
+        let mut previous = 0;
+        for u in 0..=n {
+            let offset = graph.offset(u);
+            if offset < previous || (u == 0 && offset != 0) {
+                return Err(invalid(format!("offset of vertex {} is out of order", u)));
+            }
+            previous = offset;
+        }
+        if previous != m {
+            return Err(invalid(format!("offsets end at {}, expected {}", previous, m)));
+        }
+        if let Some(i) = (0..m).find(|&i| graph.target(i) >= n) {
+            return Err(invalid(format!("edge {} targets vertex {} of {}", i, graph.target(i), n)));
+        }
+
+        Ok(graph)
+    }
+
+    fn offset(&self, u: usize) -> usize {
+        read_u64(&self.map, HEADER + 8 * u) as usize
+    }
+
+    fn target(&self, i: usize) -> usize {
+        read_u64(&self.map, HEADER + 8 * (self.n + 1 + i)) as usize
+    }
+
+    fn weight(&self, i: usize) -> f64 {
+        f64::from_bits(read_u64(&self.map, HEADER + 8 * (self.n + 1 + self.m + i)))
+    }
+}
+
+/// Out-edges of an `MmapGraph` vertex as (target, weight) pairs
+pub struct MmapNeighbors<'a> {
+    graph: &'a MmapGraph,
+    next: usize,
+    end: usize,
+}
+
+impl Iterator for MmapNeighbors<'_> {
+    type Item = (usize, f64);
+
+    fn next(&mut self) -> Option<(usize, f64)> {
+        if self.next == self.end {
+            return None;
+        }
+        let i = self.next;
+        self.next += 1;
+        Some((self.graph.target(i), self.graph.weight(i)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.next;
+        (len, Some(len))
+    }
+}
+
+impl WeightedGraph for MmapGraph {
+    type Neighbors<'a> = MmapNeighbors<'a>;
+
+    fn vertex_count(&self) -> usize {
+        self.n
+    }
+
+    fn out_neighbors(&self, u: usize) -> MmapNeighbors<'_> {
+        MmapNeighbors { graph: self, next: self.offset(u), end: self.offset(u + 1) }
+    }
+
+    fn edge_count(&self) -> usize {
+        self.m
+    }
+
+    fn out_degree(&self, u: usize) -> usize {
+        self.offset(u + 1) - self.offset(u)
+    }
+}
+
+/// Writes any backend in the format `MmapGraph::open` reads
+pub fn write_binary<G: WeightedGraph, P: AsRef<Path>>(graph: &G, path: P) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    format_binary(graph, &mut writer)?;
+    writer.flush()
+}
+
+pub fn format_binary<G: WeightedGraph, W: Write>(graph: &G, writer: &mut W) -> io::Result<()> {
+    let n = graph.vertex_count();
+    writer.write_all(MAGIC)?;
+    writer.write_all(&(n as u64).to_le_bytes())?;
+    writer.write_all(&(graph.edge_count() as u64).to_le_bytes())?;
+
+    let mut offset = 0u64;
+    writer.write_all(&offset.to_le_bytes())?;
+    for u in 0..n {
+        offset += graph.out_degree(u) as u64;
+        writer.write_all(&offset.to_le_bytes())?;
+    }
+    for u in 0..n {
+        for (v, _) in graph.out_neighbors(u) {
+            writer.write_all(&(v as u64).to_le_bytes())?;
+        }
+    }
+    for u in 0..n {
+        for (_, w) in graph.out_neighbors(u) {
+            writer.write_all(&w.to_le_bytes())?;
+        }
+    }
+    Ok(())
+}
+
+fn read_u64(bytes: &[u8], at: usize) -> u64 {
+    u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap())
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
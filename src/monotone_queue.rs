@@ -0,0 +1,148 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Priority queue for integer keys where every pushed key is at least the
+/// last popped one, which is exactly how Dijkstra uses its queue.
+///
+/// `max_step` is an upper bound on `key - last popped key` for every push
+/// (the largest edge weight for Dijkstra). Dial's buckets need it; the other
+/// queues ignore it.
+pub trait MonotoneQueue {
+    fn with_max_step(max_step: u64) -> Self;
+    fn push(&mut self, key: u64, item: usize);
+    fn pop(&mut self) -> Option<(u64, usize)>;
+    fn is_empty(&self) -> bool;
+}
+
+/// `BinaryHeap` baseline, same as the queue used by `dijkstra`
+pub struct BinaryHeapQueue {
+    heap: BinaryHeap<Reverse<(u64, usize)>>,
+}
+
+impl MonotoneQueue for BinaryHeapQueue {
+    fn with_max_step(_max_step: u64) -> Self {
+        BinaryHeapQueue { heap: BinaryHeap::new() }
+    }
+
+    fn push(&mut self, key: u64, item: usize) {
+        self.heap.push(Reverse((key, item)));
+    }
+
+    fn pop(&mut self) -> Option<(u64, usize)> {
+        self.heap.pop().map(|Reverse(entry)| entry)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
+
+/// Radix heap: bucket i holds keys whose highest bit differing from the
+/// last popped key is bit i - 1. Each key moves to a lower bucket at most
+/// 64 times, giving O(log C) amortized operations.
+pub struct RadixHeap {
+    buckets: Vec<Vec<(u64, usize)>>,
+    last: u64,
+    len: usize,
+}
+
+impl RadixHeap {
+    fn bucket(&self, key: u64) -> usize {
+        (64 - (key ^ self.last).leading_zeros()) as usize
+    }
+}
+
+impl MonotoneQueue for RadixHeap {
+    fn with_max_step(_max_step: u64) -> Self {
+        RadixHeap {
+            buckets: vec![Vec::new(); 65],
+            last: 0,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, key: u64, item: usize) {
+        debug_assert!(key >= self.last, "radix heap keys must be monotone");
+        let b = self.bucket(key);
+        self.buckets[b].push((key, item));
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<(u64, usize)> {
+        if self.len == 0 {
+            return None;
+        }
+
+        if self.buckets[0].is_empty() {
+            // Redistribute the first non-empty bucket around its minimum
+            let i = self.buckets.iter().position(|b| !b.is_empty())?;
+            let entries = std::mem::take(&mut self.buckets[i]);
+            self.last = entries.iter().map(|&(key, _)| key).min()?;
+            for (key, item) in entries {
+                let b = self.bucket(key);
+                self.buckets[b].push((key, item));
+            }
+        }
+
+        self.len -= 1;
+        self.buckets[0].pop()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Largest `max_step` `DialQueue` accepts: one bucket per key value stops
+/// paying off long before the bucket array outgrows memory
+pub const MAX_DIAL_STEP: u64 = 1 << 20;
+
+/// Dial's buckets: a cyclic array of max_step + 1 buckets, one per key
+/// value in [current, current + max_step]. O(1) push, pop scans forward.
+pub struct DialQueue {
+    buckets: Vec<Vec<usize>>,
+    current: u64,
+    len: usize,
+}
+
+impl MonotoneQueue for DialQueue {
+    /// # Panics
+    ///
+    /// If `max_step` exceeds `MAX_DIAL_STEP`.
+    fn with_max_step(max_step: u64) -> Self {
+        assert!(max_step <= MAX_DIAL_STEP,
+                "max_step {} needs more than {} Dial buckets", max_step, MAX_DIAL_STEP);
+        DialQueue {
+            buckets: vec![Vec::new(); max_step as usize + 1],
+            current: 0,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, key: u64, item: usize) {
+        debug_assert!(key >= self.current && key - self.current < self.buckets.len() as u64,
+                      "key outside Dial's bucket window");
+        let slot = (key % self.buckets.len() as u64) as usize;
+        self.buckets[slot].push(item);
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<(u64, usize)> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let slots = self.buckets.len() as u64;
+        while self.buckets[(self.current % slots) as usize].is_empty() {
+            self.current += 1;
+        }
+
+        self.len -= 1;
+        let item = self.buckets[(self.current % slots) as usize].pop()?;
+        Some((self.current, item))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
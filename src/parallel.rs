@@ -0,0 +1,200 @@
+use crate::bellman_ford::{bellman_ford, NegativeCycle};
+use crate::graph::WeightedGraph;
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Tentative distances shared between threads.
+///
+/// Each distance is stored as an order-preserving key: the sign bit is set
+/// for non-negative values and all bits are flipped for negative ones, so
+/// keys compare like the `f64`s they encode and a relaxation is a single
+/// `fetch_min`.
+pub struct AtomicDistances {
+    bits: Vec<AtomicU64>,
+}
+
+impl AtomicDistances {
+    pub fn new(n: usize) -> Self {
+        AtomicDistances {
+            bits: (0..n).map(|_| AtomicU64::new(to_key(f64::INFINITY))).collect(),
+        }
+    }
+
+    pub fn get(&self, v: usize) -> f64 {
+        from_key(self.bits[v].load(Ordering::Relaxed))
+    }
+
+    /// Lowers the distance of `v` to `d`; returns true if it improved.
+    /// NaN never improves a distance.
+    pub fn relax(&self, v: usize, d: f64) -> bool {
+        if d.is_nan() {
+            return false;
+        }
+        // Adding 0.0 turns -0.0 into 0.0, which would otherwise order below it
+        let new_key = to_key(d + 0.0);
+        self.bits[v].fetch_min(new_key, Ordering::Relaxed) > new_key
+    }
+
+    pub fn into_vec(self) -> Vec<f64> {
+        self.bits.into_iter().map(|b| from_key(b.into_inner())).collect()
+    }
+}
+
+const SIGN: u64 = 1 << 63;
+
+fn to_key(d: f64) -> u64 {
+    let bits = d.to_bits();
+    if bits & SIGN == 0 { bits | SIGN } else { !bits }
+}
+
+fn from_key(key: u64) -> f64 {
+    f64::from_bits(if key & SIGN != 0 { key & !SIGN } else { !key })
+}
+
+/// Thread pool for running the parallel solvers with a fixed thread count
+/// (0 uses rayon's default of one thread per core)
+pub fn thread_pool(threads: usize) -> rayon::ThreadPool {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .expect("failed to build thread pool")
+}
+
+fn assert_non_negative<G: WeightedGraph>(graph: &G) {
+    for u in 0..graph.vertex_count() {
+        if let Some((v, w)) = graph.out_neighbors(u).find(|&(_, w)| w < 0.0 || !w.is_finite()) {
+            panic!("parallel delta-stepping needs finite non-negative weights, edge {} -> {} has weight {}", u, v, w);
+        }
+    }
+}
+
+/// Relaxes the out-edges of every vertex in `frontier` whose weight passes
+/// `keep`, in parallel, and returns the vertices that improved (deduplicated)
+fn relax_frontier<G, F>(graph: &G, dist: &AtomicDistances, frontier: &[usize], keep: F) -> Vec<usize>
+where
+    G: WeightedGraph + Sync,
+    F: Fn(f64) -> bool + Sync,
+{
+    let mut improved: Vec<usize> = frontier
+        .par_iter()
+        .flat_map_iter(|&u| {
+            let du = dist.get(u);
+            graph
+                .out_neighbors(u)
+                .filter(|&(_, w)| keep(w))
+                .filter(move |&(v, w)| dist.relax(v, du + w))
+                .map(|(v, _)| v)
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    improved.par_sort_unstable();
+    improved.dedup();
+    improved
+}
+
+/// Multithreaded delta-stepping. Each light-edge round and the heavy-edge
+/// pass of a bucket relax their whole frontier in parallel; bucket
+/// bookkeeping between rounds is sequential. Runs on the current rayon pool,
+/// see `thread_pool`.
+///
+/// # Panics
+///
+/// If `delta` is not positive or any edge weight is negative or infinite.
+pub fn parallel_delta_stepping<G: WeightedGraph + Sync>(graph: &G, source: usize, delta: f64) -> Vec<f64> {
+    assert!(delta > 0.0, "delta must be positive");
+    assert_non_negative(graph);
+    let n = graph.vertex_count();
+    let dist = AtomicDistances::new(n);
+    let max_weight = (0..n).flat_map(|u| graph.out_neighbors(u)).map(|(_, w)| w).fold(0.0, f64::max);
+
+    // Same cyclic bucket array and Δ floor as the sequential version
+    let delta = delta.max(max_weight / (n as f64 + 1.0));
+    let slots = (max_weight / delta).floor() as usize + 2;
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); slots];
+    let mut pending = 0usize;
+
+    let bucket_of = |d: f64| (d / delta).floor() as usize;
+
+    dist.relax(source, 0.0);
+    buckets[0].push(source);
+    pending += 1;
+
+    let mut in_settled = vec![false; n];
+    let mut current = 0usize;
+
+    while pending > 0 {
+        while buckets[current % slots].is_empty() {
+            current += 1;
+        }
+
+        let requests = std::mem::take(&mut buckets[current % slots]);
+        pending -= requests.len();
+        let mut frontier: Vec<usize> = requests.into_iter().filter(|&v| bucket_of(dist.get(v)) == current).collect();
+        frontier.sort_unstable();
+        frontier.dedup();
+
+        let mut settled = Vec::new();
+
+        while !frontier.is_empty() {
+            for &u in &frontier {
+                if !in_settled[u] {
+                    in_settled[u] = true;
+                    settled.push(u);
+                }
+            }
+
+            let improved = relax_frontier(graph, &dist, &frontier, |w| w <= delta);
+
+            frontier.clear();
+            for v in improved {
+                let b = bucket_of(dist.get(v));
+                if b == current {
+                    frontier.push(v);
+                } else {
+                    buckets[b % slots].push(v);
+                    pending += 1;
+                }
+            }
+        }
+
+        for &u in &settled {
+            in_settled[u] = false;
+        }
+
+        for v in relax_frontier(graph, &dist, &settled, |w| w > delta) {
+            buckets[bucket_of(dist.get(v)) % slots].push(v);
+            pending += 1;
+        }
+
+        current += 1;
+    }
+
+    dist.into_vec()
+}
+
+/// Frontier-based Bellman-Ford: each round relaxes the out-edges of every
+/// vertex improved in the previous round, in parallel. Runs on the current
+/// rayon pool, see `thread_pool`.
+///
+/// Negative weights are fine. A vertex improved in round k was reached by a
+/// walk of at least k edges, so without a negative cycle the frontier is
+/// empty after n rounds; if it is not, the cycle is extracted with the
+/// sequential `bellman_ford` and returned as the error.
+pub fn parallel_bellman_ford<G: WeightedGraph + Sync>(graph: &G, source: usize) -> Result<Vec<f64>, NegativeCycle> {
+    let n = graph.vertex_count();
+    let dist = AtomicDistances::new(n);
+    dist.relax(source, 0.0);
+
+    let mut frontier = vec![source];
+    let mut rounds = 0usize;
+    while !frontier.is_empty() {
+        if rounds == n {
+            return bellman_ford(graph, source);
+        }
+        frontier = relax_frontier(graph, &dist, &frontier, |_| true);
+        rounds += 1;
+    }
+
+    Ok(dist.into_vec())
+}
@@ -0,0 +1,209 @@
+use crate::graph::{Graph, WeightedGraph};
+use crate::core_algorithm::CoreSolver;
+use crate::parallel::AtomicDistances;
+use rayon::prelude::*;
+use std::collections::{BinaryHeap, HashSet};
+use std::cmp::Ordering;
+use std::sync::atomic::{self, AtomicU64};
+
+#[derive(Debug, Clone)]
+struct Node {
+    id: usize,
+    dist: f64,
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.dist == other.dist
+    }
+}
+
+impl Eq for Node {}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.dist.partial_cmp(&self.dist).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Work split by one `ParallelCoreAlgorithm` run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ForkCounts {
+    /// `rayon::join` calls between the pivot and remaining sub-calls
+    pub joins: u64,
+    /// Bounded Dijkstra runs, executed concurrently within a level-0 call
+    pub base_cases: u64,
+}
+
+/// Parallel version of the core algorithm on a borrowed graph, with the
+/// parameters of `CoreSolver`.
+///
+/// Unlike the sequential recursion, a single source above level 0 still
+/// goes through FindPivots, whose k rounds are relaxed in parallel, and the
+/// pivot and remaining sub-calls of BMSSP run with `rayon::join`; base-case
+/// sources are explored concurrently. Distances are shared through
+/// `AtomicDistances`, so weights must be non-negative. Runs on the current
+/// rayon pool.
+pub struct ParallelCoreAlgorithm<'g, G: WeightedGraph = Graph> {
+    pub solver: CoreSolver<'g, G>,
+    pub dist: AtomicDistances,
+    joins: AtomicU64,
+    base_cases: AtomicU64,
+}
+
+impl<'g, G: WeightedGraph + Sync> ParallelCoreAlgorithm<'g, G> {
+    pub fn new(graph: &'g G) -> Self {
+        ParallelCoreAlgorithm {
+            solver: CoreSolver::new(graph),
+            dist: AtomicDistances::new(graph.vertex_count()),
+            joins: AtomicU64::new(0),
+            base_cases: AtomicU64::new(0),
+        }
+    }
+
+    pub fn sssp(self, source: usize) -> Vec<f64> {
+        self.sssp_with_counts(source).0
+    }
+
+    /// `sssp` that also reports how the work was split
+    pub fn sssp_with_counts(self, source: usize) -> (Vec<f64>, ForkCounts) {
+        let n = self.solver.graph.vertex_count();
+        let log_n = (n as f64).ln();
+        let l = ((log_n / self.solver.t as f64).ceil() as usize).max(1);
+
+        self.dist.relax(source, 0.0);
+        self.bmssp(l, f64::INFINITY, vec![source]);
+
+        let counts = self.counts();
+        (self.dist.into_vec(), counts)
+    }
+
+    /// Counts accumulated since construction
+    pub fn counts(&self) -> ForkCounts {
+        ForkCounts {
+            joins: self.joins.load(atomic::Ordering::Relaxed),
+            base_cases: self.base_cases.load(atomic::Ordering::Relaxed),
+        }
+    }
+
+    /// Every vertex lowered here, or a source, is expanded before this
+    /// returns: by FindPivots, by a sub-call, or by a base case. Nothing is
+    /// dropped when no pivot is found, so the result is exact.
+    pub fn bmssp(&self, level: usize, bound: f64, sources: Vec<usize>) {
+        if sources.is_empty() {
+            return;
+        }
+
+        if level == 0 {
+            self.base_cases.fetch_add(sources.len() as u64, atomic::Ordering::Relaxed);
+            sources.par_iter().for_each(|&s| self.base_case(s, bound));
+            return;
+        }
+
+        let (mut pivots, reachable) = self.find_pivots(bound, &sources);
+
+        // Non-pivot sources join the remaining set: another source may have
+        // lowered them after their out-edges were relaxed
+        pivots.sort_unstable();
+        let remaining: Vec<usize> = sources
+            .into_iter()
+            .chain(reachable)
+            .filter(|v| pivots.binary_search(v).is_err() && self.dist.get(*v) < bound)
+            .collect();
+
+        self.joins.fetch_add(1, atomic::Ordering::Relaxed);
+        rayon::join(
+            || self.bmssp(level - 1, bound / 2.0, pivots),
+            || self.bmssp(level - 1, bound, remaining),
+        );
+    }
+
+    /// FindPivots with each of the k rounds relaxed in parallel. Results are
+    /// merged in queue order, so pivots are kept in discovery order as in
+    /// `CoreWorkspace`.
+    pub fn find_pivots(&self, bound: f64, sources: &[usize]) -> (Vec<usize>, Vec<usize>) {
+        let graph = self.solver.graph;
+        let k = self.solver.k;
+        let mut pivots = Vec::new();
+        let mut reachable = Vec::new();
+        let mut visited = HashSet::new();
+        let in_sources: HashSet<usize> = sources.iter().copied().collect();
+
+        let mut queue: Vec<usize> = sources
+            .iter()
+            .copied()
+            .filter(|&s| self.dist.get(s) < bound && visited.insert(s))
+            .collect();
+
+        for _round in 0..k {
+            if queue.is_empty() {
+                break;
+            }
+
+            let results: Vec<(usize, Vec<usize>)> = queue
+                .par_iter()
+                .map(|&u| {
+                    let du = self.dist.get(u);
+                    let improved = graph
+                        .out_neighbors(u)
+                        .filter(|&(to, weight)| {
+                            let new_dist = du + weight;
+                            new_dist < bound && self.dist.relax(to, new_dist)
+                        })
+                        .map(|(to, _)| to)
+                        .collect();
+                    (u, improved)
+                })
+                .collect();
+
+            let mut next = Vec::new();
+            for (u, improved) in results {
+                if in_sources.contains(&u) && improved.len() >= k {
+                    pivots.push(u);
+                }
+                for v in improved {
+                    if visited.insert(v) {
+                        next.push(v);
+                        reachable.push(v);
+                    }
+                }
+            }
+            queue = next;
+        }
+
+        pivots.truncate((sources.len() / k).max(1));
+        (pivots, reachable)
+    }
+
+    /// Bounded Dijkstra from one source. Uses lazy deletion instead of a
+    /// processed set: another thread may lower a distance after this search
+    /// has passed the vertex, and whichever thread lowers it re-expands it.
+    pub fn base_case(&self, source: usize, bound: f64) {
+        let mut heap = BinaryHeap::new();
+
+        let ds = self.dist.get(source);
+        if ds < bound {
+            heap.push(Node { id: source, dist: ds });
+        }
+
+        while let Some(Node { id: u, dist: d }) = heap.pop() {
+            if d > self.dist.get(u) || d >= bound {
+                continue;
+            }
+
+            for (to, weight) in self.solver.graph.out_neighbors(u) {
+                let new_dist = d + weight;
+
+                if new_dist < bound && self.dist.relax(to, new_dist) {
+                    heap.push(Node { id: to, dist: new_dist });
+                }
+            }
+        }
+    }
+}
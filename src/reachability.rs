@@ -0,0 +1,110 @@
+use crate::graph::{Graph, WeightedGraph};
+use crate::solver::Solver;
+use std::collections::VecDeque;
+use std::fmt;
+
+const NONE: usize = usize::MAX;
+
+/// Subgraph induced by the vertices reachable from a source, renumbered in
+/// BFS order (the source becomes vertex 0)
+pub struct ReachableSubgraph {
+    pub graph: Graph,
+    /// Original id of each subgraph vertex
+    pub original: Vec<usize>,
+    /// Subgraph id of each original vertex, `usize::MAX` if unreachable
+    pub index: Vec<usize>,
+    pub stats: PruningStats,
+}
+
+/// How much of the graph a reachability pass removed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PruningStats {
+    pub vertices: usize,
+    pub edges: usize,
+    pub reachable_vertices: usize,
+    pub reachable_edges: usize,
+}
+
+impl PruningStats {
+    pub fn pruned_vertices(&self) -> usize {
+        self.vertices - self.reachable_vertices
+    }
+
+    pub fn pruned_edges(&self) -> usize {
+        self.edges - self.reachable_edges
+    }
+
+    /// Fraction of vertices removed, in [0, 1]
+    pub fn pruned_fraction(&self) -> f64 {
+        if self.vertices == 0 {
+            0.0
+        } else {
+            self.pruned_vertices() as f64 / self.vertices as f64
+        }
+    }
+}
+
+impl fmt::Display for PruningStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "reachable {}/{} vertices, {}/{} edges ({:.1}% of vertices pruned)",
+               self.reachable_vertices, self.vertices, self.reachable_edges, self.edges,
+               self.pruned_fraction() * 100.0)
+    }
+}
+
+impl ReachableSubgraph {
+    pub fn build<G: WeightedGraph>(graph: &G, source: usize) -> Self {
+        let mut index = vec![NONE; graph.vertex_count()];
+        let mut original = vec![source];
+        let mut queue = VecDeque::new();
+        index[source] = 0;
+        queue.push_back(source);
+
+        while let Some(u) = queue.pop_front() {
+            for (to, _) in graph.out_neighbors(u) {
+                if index[to] == NONE {
+                    index[to] = original.len();
+                    original.push(to);
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        // Every out-edge of a reachable vertex stays inside the subgraph
+        let mut subgraph = Graph::new(original.len());
+        for (new_u, &u) in original.iter().enumerate() {
+            for (to, weight) in graph.out_neighbors(u) {
+                subgraph.add_edge(new_u, index[to], weight);
+            }
+        }
+        if let Some(coords) = graph.coords() {
+            subgraph.coords = Some(original.iter().map(|&v| coords[v]).collect());
+        }
+
+        let stats = PruningStats {
+            vertices: graph.vertex_count(),
+            edges: graph.edge_count(),
+            reachable_vertices: subgraph.n,
+            reachable_edges: subgraph.m(),
+        };
+
+        ReachableSubgraph { graph: subgraph, original, index, stats }
+    }
+
+    /// Maps subgraph distances back to the original ids; pruned vertices
+    /// are unreachable
+    pub fn expand(&self, dist: &[f64]) -> Vec<f64> {
+        let mut expanded = vec![f64::INFINITY; self.index.len()];
+        for (&v, &d) in self.original.iter().zip(dist) {
+            expanded[v] = d;
+        }
+        expanded
+    }
+}
+
+/// Runs `solver` on the part of the graph reachable from `source` only
+pub fn pruned_sssp<G: WeightedGraph>(graph: &G, source: usize, solver: Solver) -> (Vec<f64>, PruningStats) {
+    let reachable = ReachableSubgraph::build(graph, source);
+    let dist = solver.run(&reachable.graph, 0);
+    (reachable.expand(&dist), reachable.stats)
+}
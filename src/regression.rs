@@ -0,0 +1,116 @@
+use std::fmt;
+
+/// Candidate growth models for a solver's cost in terms of n and m
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Model {
+    /// Binary-heap Dijkstra
+    MLogN,
+    /// The bound claimed for the improved algorithms
+    MLogTwoThirdsN,
+    /// Fibonacci-heap Dijkstra
+    MPlusNLogN,
+}
+
+impl Model {
+    pub const ALL: &'static [Model] = &[Model::MLogN, Model::MLogTwoThirdsN, Model::MPlusNLogN];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Model::MLogN => "m log n",
+            Model::MLogTwoThirdsN => "m log^(2/3) n",
+            Model::MPlusNLogN => "m + n log n",
+        }
+    }
+
+    /// The model's cost for a graph with `n` vertices and `m` edges
+    pub fn eval(&self, n: usize, m: usize) -> f64 {
+        let (n, m) = (n as f64, m as f64);
+        let log_n = n.max(2.0).ln();
+        match self {
+            Model::MLogN => m * log_n,
+            Model::MLogTwoThirdsN => m * log_n.powf(2.0 / 3.0),
+            Model::MPlusNLogN => m + n * log_n,
+        }
+    }
+}
+
+impl fmt::Display for Model {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// One measurement from a size sweep: time, operation count or any other
+/// cost observed on a graph of this size
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    pub n: usize,
+    pub m: usize,
+    pub value: f64,
+}
+
+/// Fit of `value ≈ coefficient * model(n, m)`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fit {
+    pub model: Model,
+    pub coefficient: f64,
+    /// Coefficient of determination of the log values, 1 - SS_res / SS_tot.
+    /// 1 is a perfect fit; it drops below 0 when the model does worse than
+    /// a constant.
+    pub r_squared: f64,
+}
+
+/// Fits `samples` to `model` by least squares on `ln value = ln c +
+/// ln model(n, m)`. Costs span orders of magnitude over a sweep, and on the
+/// log scale every size weighs the same instead of the largest dominating.
+/// `None` without at least two positive samples of differing value.
+pub fn fit(model: Model, samples: &[Sample]) -> Option<Fit> {
+    if samples.len() < 2 || samples.iter().any(|s| s.value <= 0.0 || !s.value.is_finite()) {
+        return None;
+    }
+    let ys: Vec<f64> = samples.iter().map(|s| s.value.ln()).collect();
+    let xs: Vec<f64> = samples.iter().map(|s| model.eval(s.n, s.m).ln()).collect();
+    let count = samples.len() as f64;
+
+    let log_coefficient = ys.iter().zip(&xs).map(|(y, x)| y - x).sum::<f64>() / count;
+    let mean = ys.iter().sum::<f64>() / count;
+    let ss_tot: f64 = ys.iter().map(|y| (y - mean).powi(2)).sum();
+    if ss_tot == 0.0 {
+        return None;
+    }
+    let ss_res: f64 = ys.iter().zip(&xs).map(|(y, x)| (y - log_coefficient - x).powi(2)).sum();
+
+    Some(Fit { model, coefficient: log_coefficient.exp(), r_squared: 1.0 - ss_res / ss_tot })
+}
+
+/// All models fitted to one series, best first
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ranking {
+    pub fits: Vec<Fit>,
+}
+
+impl Ranking {
+    /// `None` when the samples cannot be fitted (see `fit`)
+    pub fn of(samples: &[Sample]) -> Option<Ranking> {
+        let mut fits: Vec<Fit> = Model::ALL.iter().filter_map(|&model| fit(model, samples)).collect();
+        if fits.is_empty() {
+            return None;
+        }
+        fits.sort_by(|a, b| b.r_squared.total_cmp(&a.r_squared));
+        Some(Ranking { fits })
+    }
+
+    pub fn best(&self) -> &Fit {
+        &self.fits[0]
+    }
+
+    pub fn get(&self, model: Model) -> Option<&Fit> {
+        self.fits.iter().find(|fit| fit.model == model)
+    }
+
+    /// Whether the data support `model`: its R² must be within `margin` of
+    /// the best fit. Models this close cannot be told apart by the sweep.
+    pub fn supports(&self, model: Model, margin: f64) -> bool {
+        self.get(model).is_some_and(|fit| fit.r_squared >= self.best().r_squared - margin)
+    }
+}
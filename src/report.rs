@@ -0,0 +1,347 @@
+use crate::graph::Graph;
+use crate::stats::Summary;
+use std::io::{self, BufRead, Write};
+
+/// One timed solver run
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    /// Graph id, e.g. the graph spec string
+    pub graph: String,
+    pub n: usize,
+    pub m: usize,
+    pub density: f64,
+    pub solver: String,
+    pub source: usize,
+    /// Repetition index for repeated runs of the same solver and source
+    pub rep: usize,
+    /// Solve time, excluding setup
+    pub time_ms: f64,
+    /// Preparation before the solve (see `Solver::setup`)
+    pub setup_ms: f64,
+    /// Largest difference from the reference distances (`None` when not checked)
+    pub max_error: Option<f64>,
+    /// Extra named counts, such as reachable vertices or threads
+    pub counters: Vec<(String, u64)>,
+}
+
+impl Record {
+    /// Record for `graph` with n, m and density filled in
+    pub fn new(graph_id: &str, graph: &Graph, solver: &str, source: usize, time_ms: f64) -> Self {
+        let n = graph.n;
+        let m = graph.m();
+        let density = if n > 1 { m as f64 / (n as f64 * (n - 1) as f64) } else { 0.0 };
+        Record {
+            graph: graph_id.to_string(),
+            n,
+            m,
+            density,
+            solver: solver.to_string(),
+            source,
+            rep: 0,
+            time_ms,
+            setup_ms: 0.0,
+            max_error: None,
+            counters: Vec::new(),
+        }
+    }
+
+    pub fn counter(&self, name: &str) -> Option<u64> {
+        self.counters.iter().find(|(n, _)| n == name).map(|&(_, v)| v)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Format> {
+        match name {
+            "table" => Some(Format::Table),
+            "json" | "jsonl" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// Receives records as they are measured
+pub trait Renderer {
+    fn record(&mut self, record: &Record) -> io::Result<()>;
+    /// Flushes anything buffered; call once after the last record
+    fn finish(&mut self) -> io::Result<()>;
+}
+
+pub fn renderer<'a, W: Write + 'a>(format: Format, writer: W) -> Box<dyn Renderer + 'a> {
+    match format {
+        Format::Table => Box::new(TableRenderer::new(writer)),
+        Format::Json => Box::new(JsonLinesRenderer { writer }),
+        Format::Csv => Box::new(CsvRenderer { writer, header: false }),
+    }
+}
+
+/// One JSON object per line. Non-finite numbers and unchecked errors
+/// become `null`.
+pub struct JsonLinesRenderer<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> Renderer for JsonLinesRenderer<W> {
+    fn record(&mut self, r: &Record) -> io::Result<()> {
+        let counters: Vec<String> = r.counters.iter().map(|(name, v)| format!("{}:{}", json_string(name), v)).collect();
+        writeln!(self.writer,
+                 "{{\"graph\":{},\"n\":{},\"m\":{},\"density\":{},\"solver\":{},\"source\":{},\"rep\":{},\"time_ms\":{},\"setup_ms\":{},\"max_error\":{},\"counters\":{{{}}}}}",
+                 json_string(&r.graph), r.n, r.m, json_number(r.density), json_string(&r.solver), r.source, r.rep,
+                 json_number(r.time_ms), json_number(r.setup_ms), r.max_error.map_or("null".to_string(), json_number), counters.join(","))
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_number(x: f64) -> String {
+    if x.is_finite() { x.to_string() } else { "null".to_string() }
+}
+
+/// CSV with a header row. Counters go into one column as `name=value`
+/// pairs separated by `;`. `max_error` is empty when results were not
+/// checked.
+pub struct CsvRenderer<W: Write> {
+    writer: W,
+    header: bool,
+}
+
+pub const CSV_HEADER: &str = "graph,n,m,density,solver,source,rep,time_ms,setup_ms,max_error,counters";
+
+impl<W: Write> Renderer for CsvRenderer<W> {
+    fn record(&mut self, r: &Record) -> io::Result<()> {
+        if !self.header {
+            writeln!(self.writer, "{}", CSV_HEADER)?;
+            self.header = true;
+        }
+        let counters: Vec<String> = r.counters.iter().map(|(name, v)| format!("{}={}", name, v)).collect();
+        writeln!(self.writer, "{},{},{},{},{},{},{},{},{},{},{}",
+                 csv_field(&r.graph), r.n, r.m, r.density, csv_field(&r.solver), r.source, r.rep,
+                 r.time_ms, r.setup_ms, r.max_error.map(|e| e.to_string()).unwrap_or_default(), csv_field(&counters.join(";")))
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Quotes fields containing separators, quotes or line breaks
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Reads records written by `CsvRenderer`
+pub fn parse_csv<R: BufRead>(reader: R) -> io::Result<Vec<Record>> {
+    let mut lines = reader.lines();
+    match lines.next().transpose()? {
+        Some(header) if header.trim_end() == CSV_HEADER => {}
+        _ => return Err(invalid(format!("expected header '{}'", CSV_HEADER))),
+    }
+
+    let mut records = Vec::new();
+    for (i, line) in lines.enumerate() {
+        let line_number = i + 2;
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields = split_csv_line(&line).ok_or_else(|| invalid(format!("line {}: unterminated quote", line_number)))?;
+        let [graph, n, m, density, solver, source, rep, time_ms, setup_ms, max_error, counters] = fields.as_slice() else {
+            return Err(invalid(format!("line {}: expected 11 fields, found {}", line_number, fields.len())));
+        };
+
+        let counters = counters
+            .split(';')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (name, value) = pair.split_once('=').ok_or_else(|| invalid(format!("line {}: bad counter '{}'", line_number, pair)))?;
+                Ok((name.to_string(), parse_field(value, line_number)?))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        records.push(Record {
+            graph: graph.clone(),
+            n: parse_field(n, line_number)?,
+            m: parse_field(m, line_number)?,
+            density: parse_field(density, line_number)?,
+            solver: solver.clone(),
+            source: parse_field(source, line_number)?,
+            rep: parse_field(rep, line_number)?,
+            time_ms: parse_field(time_ms, line_number)?,
+            setup_ms: parse_field(setup_ms, line_number)?,
+            max_error: if max_error.is_empty() { None } else { Some(parse_field(max_error, line_number)?) },
+            counters,
+        });
+    }
+    Ok(records)
+}
+
+/// Fields of one CSV line, unquoting as `csv_field` quotes. `None` for an
+/// unterminated quote.
+fn split_csv_line(line: &str) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut chars = line.chars().peekable();
+    let mut quoted = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    if quoted {
+        return None;
+    }
+    fields.push(field);
+    Some(fields)
+}
+
+fn parse_field<T: std::str::FromStr>(field: &str, line_number: usize) -> io::Result<T> {
+    field.parse().map_err(|_| invalid(format!("line {}: cannot parse '{}'", line_number, field)))
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Human-readable summary: records are grouped by graph, and each solver
+/// gets one row summarizing all its runs (every source and repetition):
+/// median and 90th percentile solve time, mean with its 95% confidence
+/// interval, median setup time, largest error (`-` when no run was
+/// checked), and speedup of the median
+/// over the first solver of the group. When the records carry allocation
+/// counters (`peak_bytes`, `alloc_bytes`, `allocations`), the largest of
+/// each over the solver's runs is shown as well.
+pub struct TableRenderer<W: Write> {
+    writer: W,
+    pending: Vec<Record>,
+}
+
+impl<W: Write> TableRenderer<W> {
+    pub fn new(writer: W) -> Self {
+        TableRenderer { writer, pending: Vec::new() }
+    }
+
+    fn flush_group(&mut self) -> io::Result<()> {
+        let Some(first) = self.pending.first() else {
+            return Ok(());
+        };
+        let sources = {
+            let mut sources: Vec<usize> = self.pending.iter().map(|r| r.source).collect();
+            sources.sort_unstable();
+            sources.dedup();
+            sources.len()
+        };
+
+        let memory = self.pending.iter().any(|r| r.counter("peak_bytes").is_some());
+
+        writeln!(self.writer, "\nGraph: {} ({} vertices, {} edges, {} sources)", first.graph, first.n, first.m, sources)?;
+        write!(self.writer, "{:<25} {:<6} {:<12} {:<12} {:<20} {:<12} {:<10} {:<10}",
+               "Solver", "Runs", "Median (ms)", "P90 (ms)", "Mean ± 95% CI (ms)", "Setup (ms)", "Max Diff", "Speedup")?;
+        if memory {
+            write!(self.writer, " {:<12} {:<12} {:<10}", "Peak (KiB)", "Alloc (KiB)", "Allocs")?;
+        }
+        writeln!(self.writer)?;
+        writeln!(self.writer, "{}", "-".repeat(if memory { 149 } else { 112 }))?;
+
+        // Solvers in order of first appearance
+        let mut solvers: Vec<&str> = Vec::new();
+        for r in &self.pending {
+            if !solvers.contains(&r.solver.as_str()) {
+                solvers.push(&r.solver);
+            }
+        }
+
+        let mut baseline = None;
+        let mut warnings = Vec::new();
+        for solver in solvers {
+            let runs: Vec<&Record> = self.pending.iter().filter(|r| r.solver == solver).collect();
+            let times: Vec<f64> = runs.iter().map(|r| r.time_ms).collect();
+            let setups: Vec<f64> = runs.iter().map(|r| r.setup_ms).collect();
+            let max_error = runs.iter().filter_map(|r| r.max_error).reduce(f64::max);
+            let (Some(time), Some(setup)) = (Summary::of(&times), Summary::of(&setups)) else {
+                continue;
+            };
+            let baseline_time = *baseline.get_or_insert(time.median);
+
+            let mean = if time.count > 1 {
+                format!("{:.3} ± {:.3}", time.mean, time.ci95)
+            } else {
+                format!("{:.3}", time.mean)
+            };
+            let diff = max_error.map_or("-".to_string(), |e| format!("{:.2e}", e));
+            write!(self.writer, "{:<25} {:<6} {:<12.3} {:<12.3} {:<20} {:<12.3} {:<10} {:<10}",
+                   solver, time.count, time.median, time.p90, mean, setup.median, diff,
+                   format!("{:.2}x", baseline_time / time.median))?;
+            if memory {
+                let largest = |name| runs.iter().filter_map(|r| r.counter(name)).max().unwrap_or(0);
+                write!(self.writer, " {:<12.1} {:<12.1} {:<10}", largest("peak_bytes") as f64 / 1024.0,
+                       largest("alloc_bytes") as f64 / 1024.0, largest("allocations"))?;
+            }
+            writeln!(self.writer)?;
+            if let Some(max_error) = max_error.filter(|&e| e > 1e-9) {
+                warnings.push(format!("WARNING: {} results differ by {:.2e}", solver, max_error));
+            }
+            if time.count > 1 && time.relative_ci() > 0.1 {
+                warnings.push(format!("NOTE: {} timings are noisy (95% CI ±{:.0}% of the mean), add --reps or sources",
+                                      solver, time.relative_ci() * 100.0));
+            }
+        }
+        for warning in warnings {
+            writeln!(self.writer, "{}", warning)?;
+        }
+
+        self.pending.clear();
+        Ok(())
+    }
+}
+
+impl<W: Write> Renderer for TableRenderer<W> {
+    fn record(&mut self, record: &Record) -> io::Result<()> {
+        if self.pending.first().is_some_and(|r| r.graph != record.graph) {
+            self.flush_group()?;
+        }
+        self.pending.push(record.clone());
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.flush_group()?;
+        self.writer.flush()
+    }
+}
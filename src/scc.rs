@@ -0,0 +1,264 @@
+use crate::graph::{Graph, WeightedGraph};
+use std::collections::BinaryHeap;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone)]
+struct Node {
+    id: usize,
+    dist: f64,
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.dist == other.dist
+    }
+}
+
+impl Eq for Node {}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.dist.partial_cmp(&self.dist).unwrap_or(Ordering::Equal)
+    }
+}
+
+const NONE: usize = usize::MAX;
+
+/// Strongly connected components. Ids are a topological order of the
+/// condensation: every edge between components goes from a lower id to a
+/// higher one.
+#[derive(Debug, Clone)]
+pub struct Components {
+    pub count: usize,
+    pub component: Vec<usize>,
+}
+
+impl Components {
+    /// Vertices of each component, by id
+    pub fn members(&self) -> Vec<Vec<usize>> {
+        let mut members = vec![Vec::new(); self.count];
+        for (v, &c) in self.component.iter().enumerate() {
+            members[c].push(v);
+        }
+        members
+    }
+
+    pub fn sizes(&self) -> Vec<usize> {
+        let mut sizes = vec![0; self.count];
+        for &c in &self.component {
+            sizes[c] += 1;
+        }
+        sizes
+    }
+}
+
+/// Tarjan's algorithm with an explicit DFS stack, O(n + m)
+pub fn tarjan_scc<G: WeightedGraph>(graph: &G) -> Components {
+    let n = graph.vertex_count();
+    let mut index = vec![NONE; n];
+    let mut low = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut component = vec![NONE; n];
+    let mut count = 0;
+    let mut next_index = 0;
+
+    for root in 0..n {
+        if index[root] != NONE {
+            continue;
+        }
+        // (vertex, its remaining out-edges)
+        let mut dfs = vec![(root, graph.out_neighbors(root))];
+        index[root] = next_index;
+        low[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some((v, edges)) = dfs.last_mut() {
+            let v = *v;
+            if let Some((w, _)) = edges.next() {
+                if index[w] == NONE {
+                    index[w] = next_index;
+                    low[w] = next_index;
+                    next_index += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    dfs.push((w, graph.out_neighbors(w)));
+                } else if on_stack[w] {
+                    low[v] = low[v].min(index[w]);
+                }
+                continue;
+            }
+
+            dfs.pop();
+            if low[v] == index[v] {
+                while let Some(w) = stack.pop() {
+                    on_stack[w] = false;
+                    component[w] = count;
+                    if w == v {
+                        break;
+                    }
+                }
+                count += 1;
+            }
+            if let Some(&(parent, _)) = dfs.last() {
+                low[parent] = low[parent].min(low[v]);
+            }
+        }
+    }
+
+    // Tarjan completes sink components first; flip to topological order
+    for c in component.iter_mut() {
+        *c = count - 1 - *c;
+    }
+    Components { count, component }
+}
+
+/// Kosaraju's algorithm: DFS finish order on the graph, then components
+/// are collected on the reverse graph in decreasing finish time, O(n + m)
+pub fn kosaraju_scc<G: WeightedGraph>(graph: &G) -> Components {
+    let n = graph.vertex_count();
+    let mut visited = vec![false; n];
+    let mut finish_order = Vec::with_capacity(n);
+
+    for root in 0..n {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let mut dfs = vec![(root, graph.out_neighbors(root))];
+        while let Some((v, edges)) = dfs.last_mut() {
+            if let Some((w, _)) = edges.next() {
+                if !visited[w] {
+                    visited[w] = true;
+                    dfs.push((w, graph.out_neighbors(w)));
+                }
+            } else {
+                finish_order.push(*v);
+                dfs.pop();
+            }
+        }
+    }
+
+    // Only the reverse adjacency is needed, not a reversed copy of the graph
+    let mut reverse = vec![Vec::new(); n];
+    for u in 0..n {
+        for (v, _) in graph.out_neighbors(u) {
+            reverse[v].push(u);
+        }
+    }
+    let mut component = vec![NONE; n];
+    let mut count = 0;
+
+    for &root in finish_order.iter().rev() {
+        if component[root] != NONE {
+            continue;
+        }
+        component[root] = count;
+        let mut stack = vec![root];
+        while let Some(v) = stack.pop() {
+            for &w in &reverse[v] {
+                if component[w] == NONE {
+                    component[w] = count;
+                    stack.push(w);
+                }
+            }
+        }
+        count += 1;
+    }
+
+    Components { count, component }
+}
+
+/// Condensation of a graph: one vertex per strongly connected component,
+/// joined by the lightest edge between each pair of components
+pub struct Condensation {
+    pub components: Components,
+    pub dag: Graph,
+}
+
+impl Condensation {
+    pub fn build<G: WeightedGraph>(graph: &G) -> Self {
+        let components = tarjan_scc(graph);
+
+        let mut cross: Vec<(usize, usize, f64)> = Vec::new();
+        for u in 0..graph.vertex_count() {
+            let cu = components.component[u];
+            for (v, weight) in graph.out_neighbors(u) {
+                let cv = components.component[v];
+                if cu != cv {
+                    cross.push((cu, cv, weight));
+                }
+            }
+        }
+        // Sorting by (from, to, weight) puts the lightest parallel edge first
+        cross.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)).then(a.2.total_cmp(&b.2)));
+        cross.dedup_by_key(|&mut (cu, cv, _)| (cu, cv));
+
+        let mut dag = Graph::new(components.count);
+        for (cu, cv, w) in cross {
+            dag.add_edge(cu, cv, w);
+        }
+
+        Condensation { components, dag }
+    }
+
+    /// Number of edges between different components
+    pub fn cross_edges(&self) -> usize {
+        self.dag.m()
+    }
+}
+
+/// SSSP by components: each component is solved with a Dijkstra restricted
+/// to its own vertices, seeded with the distances that crossed into it, and
+/// then its outgoing cross edges are relaxed. Components are processed in
+/// topological order starting at the source's, so earlier ones are never
+/// revisited. Weights must be non-negative.
+pub fn condensation_sssp<G: WeightedGraph>(graph: &G, source: usize) -> Vec<f64> {
+    condensation_sssp_with(graph, &tarjan_scc(graph), source)
+}
+
+/// Same as `condensation_sssp` with precomputed components
+pub fn condensation_sssp_with<G: WeightedGraph>(graph: &G, components: &Components, source: usize) -> Vec<f64> {
+    let mut dist = vec![f64::INFINITY; graph.vertex_count()];
+    dist[source] = 0.0;
+
+    let members = components.members();
+    let first = components.component[source];
+
+    for (c, vertices) in members.iter().enumerate().skip(first) {
+        let mut heap: BinaryHeap<Node> = vertices
+            .iter()
+            .filter(|&&v| dist[v] < f64::INFINITY)
+            .map(|&v| Node { id: v, dist: dist[v] })
+            .collect();
+        if heap.is_empty() {
+            continue;
+        }
+
+        while let Some(Node { id: u, dist: d }) = heap.pop() {
+            if d > dist[u] {
+                continue;
+            }
+            for (to, weight) in graph.out_neighbors(u) {
+                let new_dist = d + weight;
+                if new_dist < dist[to] {
+                    dist[to] = new_dist;
+                    // Cross edges only seed later components
+                    if components.component[to] == c {
+                        heap.push(Node { id: to, dist: new_dist });
+                    }
+                }
+            }
+        }
+    }
+
+    dist
+}
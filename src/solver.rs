@@ -0,0 +1,142 @@
+use crate::graph::{Graph, WeightedGraph};
+use crate::dijkstra::dijkstra;
+use crate::improved_sssp::improved_sssp;
+use crate::improved_sssp_v2::improved_sssp_v2;
+use crate::core_algorithm::CoreSolver;
+use crate::delta_stepping::{auto_delta, delta_stepping, delta_stepping_split, EdgeSplit};
+use crate::decrease_key_dijkstra::{dary_dijkstra, fibonacci_dijkstra, pairing_dijkstra};
+use crate::csr::CsrGraph;
+use crate::parallel::{parallel_bellman_ford, parallel_delta_stepping};
+use crate::parallel_core::ParallelCoreAlgorithm;
+use crate::scc::{condensation_sssp, condensation_sssp_with, tarjan_scc, Components};
+
+const NON_NEGATIVE: &str = "registry solvers need non-negative weights, found a negative cycle";
+
+/// Single-source solvers for non-negative weights that can be selected by
+/// name. Every entry returns exact distances, so any of them can stand in
+/// for another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Solver {
+    Dijkstra,
+    Improved,
+    ImprovedV2,
+    Core,
+    DeltaStepping,
+    Fibonacci,
+    Pairing,
+    DaryHeap,
+    ParallelDeltaStepping,
+    ParallelBellmanFord,
+    ParallelCore,
+    Condensation,
+}
+
+impl Solver {
+    pub const ALL: &'static [Solver] = &[
+        Solver::Dijkstra,
+        Solver::Improved,
+        Solver::ImprovedV2,
+        Solver::Core,
+        Solver::DeltaStepping,
+        Solver::Fibonacci,
+        Solver::Pairing,
+        Solver::DaryHeap,
+        Solver::ParallelDeltaStepping,
+        Solver::ParallelBellmanFord,
+        Solver::ParallelCore,
+        Solver::Condensation,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Solver::Dijkstra => "dijkstra",
+            Solver::Improved => "improved",
+            Solver::ImprovedV2 => "improved-v2",
+            Solver::Core => "core",
+            Solver::DeltaStepping => "delta-stepping",
+            Solver::Fibonacci => "fibonacci",
+            Solver::Pairing => "pairing",
+            Solver::DaryHeap => "dary-heap",
+            Solver::ParallelDeltaStepping => "parallel-delta-stepping",
+            Solver::ParallelBellmanFord => "parallel-bellman-ford",
+            Solver::ParallelCore => "parallel-core",
+            Solver::Condensation => "condensation",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Solver> {
+        Solver::ALL.iter().copied().find(|s| s.name() == name)
+    }
+
+    /// Runs the solver from `source`. Parallel solvers use the current rayon pool.
+    pub fn run(&self, graph: &Graph, source: usize) -> Vec<f64> {
+        self.setup(graph).solve(source)
+    }
+
+    /// Runs the solver on any graph backend, reading `graph` directly
+    /// without copying it. `Sync` because the parallel solvers share it
+    /// between threads.
+    pub fn run_on<G: WeightedGraph + Sync>(&self, graph: &G, source: usize) -> Vec<f64> {
+        match self {
+            Solver::Dijkstra => dijkstra(graph, source),
+            Solver::Improved => improved_sssp(graph, source),
+            Solver::ImprovedV2 => improved_sssp_v2(graph, source),
+            Solver::Core => CoreSolver::new(graph).sssp(source),
+            Solver::DeltaStepping => delta_stepping(graph, source),
+            Solver::Fibonacci => fibonacci_dijkstra(graph, source),
+            Solver::Pairing => pairing_dijkstra(graph, source),
+            Solver::DaryHeap => dary_dijkstra(graph, source),
+            Solver::ParallelDeltaStepping => parallel_delta_stepping(graph, source, auto_delta(graph)),
+            Solver::ParallelBellmanFord => parallel_bellman_ford(graph, source).expect(NON_NEGATIVE),
+            Solver::ParallelCore => ParallelCoreAlgorithm::new(graph).sssp(source),
+            Solver::Condensation => condensation_sssp(graph, source),
+        }
+    }
+
+    /// Does the preparation a run needs before the search itself:
+    /// converting to CSR, splitting edges or computing components. Timing this separately from `Instance::solve`
+    /// keeps setup costs out of the solve time.
+    pub fn setup<'a>(&self, graph: &'a Graph) -> Instance<'a> {
+        match self {
+            Solver::Dijkstra => Instance::Direct(dijkstra, graph),
+            Solver::Improved => Instance::Direct(improved_sssp, graph),
+            Solver::ImprovedV2 => Instance::Direct(improved_sssp_v2, graph),
+            Solver::Core => Instance::Core(CoreSolver::new(graph)),
+            Solver::DeltaStepping => Instance::DeltaStepping(EdgeSplit::new(graph, auto_delta(graph))),
+            Solver::Fibonacci => Instance::Direct(fibonacci_dijkstra, graph),
+            Solver::Pairing => Instance::Direct(pairing_dijkstra, graph),
+            Solver::DaryHeap => Instance::Direct(dary_dijkstra, graph),
+            Solver::ParallelDeltaStepping => {
+                Instance::ParallelDeltaStepping(CsrGraph::from_graph(graph), auto_delta(graph))
+            }
+            Solver::ParallelBellmanFord => Instance::ParallelBellmanFord(CsrGraph::from_graph(graph)),
+            Solver::ParallelCore => Instance::ParallelCore(ParallelCoreAlgorithm::new(graph)),
+            Solver::Condensation => Instance::Condensation(graph, tarjan_scc(graph)),
+        }
+    }
+}
+
+/// A solver prepared by `Solver::setup`, good for one run
+pub enum Instance<'a> {
+    Direct(fn(&Graph, usize) -> Vec<f64>, &'a Graph),
+    Core(CoreSolver<'a>),
+    DeltaStepping(EdgeSplit),
+    ParallelDeltaStepping(CsrGraph, f64),
+    ParallelBellmanFord(CsrGraph),
+    ParallelCore(ParallelCoreAlgorithm<'a>),
+    Condensation(&'a Graph, Components),
+}
+
+impl Instance<'_> {
+    pub fn solve(self, source: usize) -> Vec<f64> {
+        match self {
+            Instance::Direct(solve, graph) => solve(graph, source),
+            Instance::Core(core) => core.sssp(source),
+            Instance::DeltaStepping(split) => delta_stepping_split(&split, source),
+            Instance::ParallelDeltaStepping(csr, delta) => parallel_delta_stepping(&csr, source, delta),
+            Instance::ParallelBellmanFord(csr) => parallel_bellman_ford(&csr, source).expect(NON_NEGATIVE),
+            Instance::ParallelCore(core) => core.sssp(source),
+            Instance::Condensation(graph, components) => condensation_sssp_with(graph, &components, source),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+/// Summary statistics of repeated timing samples
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub mean: f64,
+    /// Sample standard deviation (n - 1 denominator)
+    pub std_dev: f64,
+    pub min: f64,
+    pub median: f64,
+    pub p90: f64,
+    pub max: f64,
+    /// Half-width of the 95% confidence interval of the mean (Student t)
+    pub ci95: f64,
+}
+
+impl Summary {
+    /// `None` for an empty sample
+    pub fn of(samples: &[f64]) -> Option<Summary> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+
+        let count = sorted.len();
+        let mean = sorted.iter().sum::<f64>() / count as f64;
+        let std_dev = if count > 1 {
+            (sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (count - 1) as f64).sqrt()
+        } else {
+            0.0
+        };
+        let ci95 = if count > 1 {
+            t_quantile_975(count - 1) * std_dev / (count as f64).sqrt()
+        } else {
+            f64::INFINITY
+        };
+
+        Some(Summary {
+            count,
+            mean,
+            std_dev,
+            min: sorted[0],
+            median: percentile(&sorted, 50.0),
+            p90: percentile(&sorted, 90.0),
+            max: sorted[count - 1],
+            ci95,
+        })
+    }
+
+    /// Relative half-width of the confidence interval, e.g. 0.05 for ±5%
+    pub fn relative_ci(&self) -> f64 {
+        self.ci95 / self.mean
+    }
+}
+
+/// `p`-th percentile (0..=100) of sorted samples, interpolating linearly
+/// between the two closest ranks
+pub fn percentile(sorted: &[f64], p: f64) -> f64 {
+    assert!(!sorted.is_empty(), "percentile of an empty sample");
+    let rank = p.clamp(0.0, 100.0) / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+/// 97.5% quantile of Student's t distribution with `df` degrees of freedom,
+/// i.e. the multiplier for a two-sided 95% interval. Exact table values up
+/// to 30, then linear in 1/df between the rows for 30, 40, 60, 120 and
+/// infinity, which is within 0.001 of the true quantile.
+pub fn t_quantile_975(df: usize) -> f64 {
+    const TABLE: [f64; 30] = [
+        12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228,
+        2.201, 2.179, 2.160, 2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086,
+        2.080, 2.074, 2.069, 2.064, 2.060, 2.056, 2.052, 2.048, 2.045, 2.042,
+    ];
+    // (df, quantile), with usize::MAX standing for the normal limit
+    const ROWS: [(usize, f64); 5] = [(30, 2.042), (40, 2.021), (60, 2.000), (120, 1.980), (usize::MAX, 1.960)];
+
+    match df {
+        0 => f64::INFINITY,
+        1..=30 => TABLE[df - 1],
+        _ => {
+            let upper = ROWS.iter().position(|&(row, _)| df <= row).unwrap_or(ROWS.len() - 1);
+            let ((a, t_a), (b, t_b)) = (ROWS[upper - 1], ROWS[upper]);
+            let inverse = |df: usize| if df == usize::MAX { 0.0 } else { 1.0 / df as f64 };
+            t_a + (t_b - t_a) * (inverse(a) - inverse(df)) / (inverse(a) - inverse(b))
+        }
+    }
+}
@@ -0,0 +1,94 @@
+/// Per-vertex values that reset in O(1): each slot carries the epoch it was
+/// written in, and slots from older epochs read as the default. Lets a
+/// solver reuse one buffer across queries without refilling all n entries.
+#[derive(Debug, Clone)]
+pub struct EpochVec<T: Copy> {
+    values: Vec<T>,
+    stamps: Vec<u32>,
+    epoch: u32,
+    default: T,
+}
+
+impl<T: Copy> EpochVec<T> {
+    pub fn new(n: usize, default: T) -> Self {
+        EpochVec { values: vec![default; n], stamps: vec![0; n], epoch: 1, default }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Sets every slot back to the default
+    pub fn reset(&mut self) {
+        self.epoch = next_epoch(self.epoch, &mut self.stamps);
+    }
+
+    pub fn get(&self, v: usize) -> T {
+        if self.stamps[v] == self.epoch { self.values[v] } else { self.default }
+    }
+
+    pub fn set(&mut self, v: usize, value: T) {
+        self.values[v] = value;
+        self.stamps[v] = self.epoch;
+    }
+
+    /// Whether `v` was written since the last reset
+    pub fn is_set(&self, v: usize) -> bool {
+        self.stamps[v] == self.epoch
+    }
+
+    /// Copies all current values into `out`, which must have `len()` slots
+    pub fn write_to(&self, out: &mut [T]) {
+        for (v, slot) in out.iter_mut().enumerate() {
+            *slot = self.get(v);
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<T> {
+        (0..self.len()).map(|v| self.get(v)).collect()
+    }
+}
+
+/// Set of vertices with O(1) `clear`, on the same scheme as `EpochVec`
+#[derive(Debug, Clone)]
+pub struct EpochSet {
+    stamps: Vec<u32>,
+    epoch: u32,
+}
+
+impl EpochSet {
+    pub fn new(n: usize) -> Self {
+        EpochSet { stamps: vec![0; n], epoch: 1 }
+    }
+
+    pub fn clear(&mut self) {
+        self.epoch = next_epoch(self.epoch, &mut self.stamps);
+    }
+
+    /// Returns whether `v` was newly inserted
+    pub fn insert(&mut self, v: usize) -> bool {
+        let new = self.stamps[v] != self.epoch;
+        self.stamps[v] = self.epoch;
+        new
+    }
+
+    pub fn contains(&self, v: usize) -> bool {
+        self.stamps[v] == self.epoch
+    }
+}
+
+/// Advances an epoch, wiping the stamps once every 2^32 - 1 resets when the
+/// counter wraps so no stale stamp can match again
+fn next_epoch(epoch: u32, stamps: &mut [u32]) -> u32 {
+    match epoch.checked_add(1) {
+        Some(next) => next,
+        None => {
+            stamps.fill(0);
+            1
+        }
+    }
+}
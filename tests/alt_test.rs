@@ -0,0 +1,137 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::alt::{alt, LandmarkSelection, Landmarks, SizeMismatch};
+use shortest_path_validation::astar::check_heuristic;
+
+fn assert_matches_dijkstra(graph: &Graph, landmarks: &Landmarks) {
+    for s in 0..5 {
+        let expected = dijkstra(graph, s);
+        for (t, &want) in expected.iter().enumerate() {
+            let d = alt(graph, landmarks, s, t).unwrap();
+            if want.is_finite() {
+                assert!((d - want).abs() < 1e-9, "Distance mismatch for {} -> {}: {} vs {}", s, t, d, want);
+            } else {
+                assert!(d.is_infinite());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lower_bounds_on_path() {
+    // 0 -> 1 -> 2 -> 3 with unit weights, landmark at 0
+    let mut graph = Graph::new(4);
+    for i in 0..3 {
+        graph.add_edge(i, i + 1, 1.0);
+    }
+
+    let landmarks = Landmarks::from_landmarks(&graph, vec![0]);
+    assert_eq!(landmarks.lower_bound(1, 3), 2.0);
+    assert_eq!(landmarks.lower_bound(0, 2), 2.0);
+    assert_eq!(landmarks.lower_bound(3, 3), 0.0);
+}
+
+#[test]
+fn test_all_selections_are_exact() {
+    let graph = Graph::generate_random(200, 0.05, 10.0);
+
+    for selection in [LandmarkSelection::Random, LandmarkSelection::Farthest, LandmarkSelection::Avoid] {
+        let landmarks = Landmarks::build(&graph, 4, selection);
+        assert_eq!(landmarks.len(), 4, "{:?} should pick 4 landmarks", selection);
+
+        let mut unique = landmarks.landmarks.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), 4, "{:?} picked duplicate landmarks", selection);
+
+        assert!(check_heuristic(&graph, &landmarks, 7).is_consistent());
+        assert_matches_dijkstra(&graph, &landmarks);
+    }
+}
+
+#[test]
+fn test_save_and_load() {
+    let graph = Graph::generate_geometric(150, 0.2);
+    let landmarks = Landmarks::build(&graph, 3, LandmarkSelection::Avoid);
+
+    let path = std::env::temp_dir().join(format!("alt_test_{}.bin", std::process::id()));
+    landmarks.save(&path).unwrap();
+    let loaded = Landmarks::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.landmarks, landmarks.landmarks);
+    assert_eq!(loaded.n(), graph.n);
+    for v in 0..graph.n {
+        assert_eq!(loaded.lower_bound(v, 0), landmarks.lower_bound(v, 0));
+    }
+    assert_matches_dijkstra(&graph, &loaded);
+}
+
+#[test]
+fn test_load_rejects_bad_headers() {
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(1, 2, 1.0);
+    let path = std::env::temp_dir().join(format!("alt_test_headers_{}.bin", std::process::id()));
+    Landmarks::from_landmarks(&graph, vec![0]).save(&path).unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+
+    let load = |bytes: &[u8]| {
+        std::fs::write(&path, bytes).unwrap();
+        Landmarks::load(&path).map(|l| l.n())
+    };
+    assert_eq!(load(&bytes).unwrap(), 3);
+
+    // n * k overflows; must fail without trying to allocate
+    let mut huge = bytes.clone();
+    huge[4..12].copy_from_slice(&u64::MAX.to_le_bytes());
+    huge[12..20].copy_from_slice(&2u64.to_le_bytes());
+    assert_eq!(load(&huge).unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+
+    // Header claims more entries than the file holds
+    let mut long = bytes.clone();
+    long[4..12].copy_from_slice(&1000u64.to_le_bytes());
+    assert_eq!(load(&long).unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    assert!(load(&bytes[..bytes.len() - 8]).is_err());
+
+    // Landmark id past n
+    let mut bad_landmark = bytes.clone();
+    bad_landmark[20..28].copy_from_slice(&3u64.to_le_bytes());
+    let err = load(&bad_landmark).unwrap_err();
+    assert!(err.to_string().contains("landmark 3"), "{}", err);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_bounds_consistent_with_unreachable_landmark() {
+    // Target 2 reaches landmark 3, vertex 1 does not; the edge 0 -> 1 must
+    // not see h(0) = 5 - 1 against h(1) = 0
+    let mut graph = Graph::new(4);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(0, 3, 5.0);
+    graph.add_edge(2, 3, 1.0);
+    let landmarks = Landmarks::from_landmarks(&graph, vec![3]);
+
+    assert!(landmarks.lower_bound(1, 2).is_infinite());
+    let check = check_heuristic(&graph, &landmarks, 2);
+    assert!(check.is_consistent(), "{:?}", check.inconsistent_edges);
+    assert!(check.is_admissible());
+    assert!(alt(&graph, &landmarks, 0, 2).unwrap().is_infinite());
+}
+
+#[test]
+fn test_table_for_another_graph_is_rejected() {
+    let graph = Graph::generate_random(50, 0.1, 10.0);
+    let path = std::env::temp_dir().join(format!("alt_test_mismatch_{}.bin", std::process::id()));
+    Landmarks::build(&graph, 2, LandmarkSelection::Farthest).save(&path).unwrap();
+    let loaded = Landmarks::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(loaded.validate_for(&graph).is_ok());
+    let larger = Graph::generate_random(80, 0.1, 10.0);
+    let mismatch = SizeMismatch { landmarks: 50, graph: 80 };
+    assert_eq!(loaded.validate_for(&larger), Err(mismatch));
+    assert_eq!(alt(&larger, &loaded, 0, 79), Err(mismatch));
+    assert_eq!(alt(&Graph::new(10), &loaded, 0, 9), Err(SizeMismatch { landmarks: 50, graph: 10 }));
+}
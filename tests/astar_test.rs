@@ -0,0 +1,88 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::astar::{astar, check_heuristic, warn_if_inadmissible, EuclideanHeuristic, Heuristic, ZeroHeuristic};
+
+/// Claims every vertex is 100 away from the target
+struct Overestimate;
+
+impl Heuristic for Overestimate {
+    fn estimate(&self, v: usize, target: usize) -> f64 {
+        if v == target { 0.0 } else { 100.0 }
+    }
+}
+
+#[test]
+fn test_zero_heuristic_matches_dijkstra() {
+    let graph = Graph::generate_random(50, 0.1, 10.0);
+    let expected = dijkstra(&graph, 0);
+
+    for (t, &want) in expected.iter().enumerate() {
+        let d = astar(&graph, 0, t, &ZeroHeuristic);
+        if want.is_finite() {
+            assert!((d - want).abs() < 1e-9, "Distance mismatch at vertex {}: {} vs {}", t, d, want);
+        } else {
+            assert!(d.is_infinite());
+        }
+    }
+}
+
+#[test]
+fn test_euclidean_on_geometric_graph() {
+    let graph = Graph::generate_geometric(300, 0.15);
+    let heuristic = EuclideanHeuristic::from_graph(&graph).expect("geometric graph has coordinates");
+    assert!(heuristic.scale() >= 1.0, "weights are at least the straight-line distance");
+
+    for s in 0..5 {
+        let expected = dijkstra(&graph, s);
+        for t in (0..graph.n).step_by(17) {
+            let d = astar(&graph, s, t, &heuristic);
+            if expected[t].is_finite() {
+                assert!((d - expected[t]).abs() < 1e-9,
+                        "Distance mismatch for {} -> {}: {} vs {}", s, t, d, expected[t]);
+            } else {
+                assert!(d.is_infinite());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_euclidean_requires_coordinates() {
+    let graph = Graph::new(3);
+    assert!(EuclideanHeuristic::from_graph(&graph).is_none());
+}
+
+#[test]
+fn test_check_heuristic() {
+    let graph = Graph::generate_geometric(100, 0.2);
+    let heuristic = EuclideanHeuristic::from_graph(&graph).unwrap();
+    let check = check_heuristic(&graph, &heuristic, 0);
+    assert!(check.is_consistent());
+    assert!(check.is_admissible());
+
+    let mut path = Graph::new(3);
+    path.add_edge(0, 1, 1.0);
+    path.add_edge(1, 2, 1.0);
+    let check = check_heuristic(&path, &Overestimate, 2);
+    assert!(!check.is_admissible());
+    assert_eq!(check.inadmissible_vertices, vec![0, 1]);
+    assert!((check.max_overestimate - 99.0).abs() < 1e-9);
+    assert_eq!(check.inconsistent_edges, vec![(1, 2)]);
+}
+
+#[test]
+fn test_warning_for_overestimating_heuristic() {
+    let mut path = Graph::new(3);
+    path.add_edge(0, 1, 1.0);
+    path.add_edge(1, 2, 1.0);
+
+    let check = warn_if_inadmissible(&path, &Overestimate, 2);
+    let warning = check.warning().expect("overestimating heuristic should warn");
+    assert!(warning.contains("inadmissible for target 2"), "{}", warning);
+    assert!(warning.contains("at 2 vertices"), "{}", warning);
+
+    let graph = Graph::generate_geometric(100, 0.2);
+    let heuristic = EuclideanHeuristic::from_graph(&graph).unwrap();
+    assert_eq!(warn_if_inadmissible(&graph, &heuristic, 0).warning(), None);
+    assert_eq!(warn_if_inadmissible(&path, &ZeroHeuristic, 2).warning(), None);
+}
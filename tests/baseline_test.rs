@@ -0,0 +1,56 @@
+use shortest_path_validation::baseline::{summarize, Comparison};
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::report::Record;
+
+/// One record per time for `solver` on graph `id`
+fn runs(id: &str, solver: &str, times: &[f64]) -> Vec<Record> {
+    let graph = Graph::new(2);
+    times.iter().enumerate().map(|(rep, &t)| {
+        let mut record = Record::new(id, &graph, solver, 0, t);
+        record.rep = rep;
+        record
+    }).collect()
+}
+
+#[test]
+fn test_summarize() {
+    let mut records = runs("a", "dijkstra", &[3.0, 1.0, 2.0]);
+    records.extend(runs("a", "core", &[5.0]));
+    records.extend(runs("b", "dijkstra", &[4.0, 8.0]));
+
+    let timings = summarize(&records);
+    let keys: Vec<(&str, &str, usize, f64)> = timings.iter()
+        .map(|t| (t.graph.as_str(), t.solver.as_str(), t.runs, t.median_ms))
+        .collect();
+    assert_eq!(keys, vec![("a", "dijkstra", 3, 2.0), ("a", "core", 1, 5.0), ("b", "dijkstra", 2, 6.0)]);
+}
+
+#[test]
+fn test_comparison() {
+    let mut baseline = runs("a", "dijkstra", &[1.0, 1.0, 1.0]);
+    baseline.extend(runs("a", "core", &[2.0, 2.0]));
+    baseline.extend(runs("b", "dijkstra", &[1.0]));
+
+    let mut current = runs("a", "dijkstra", &[0.9, 1.0, 1.1]);
+    current.extend(runs("a", "core", &[2.4, 2.6, 2.5]));
+    current.extend(runs("c", "dijkstra", &[1.0]));
+
+    let comparison = Comparison::new(&baseline, &current);
+    assert_eq!(comparison.changes.len(), 2);
+    assert_eq!(comparison.missing, vec![("b".to_string(), "dijkstra".to_string())]);
+    assert_eq!(comparison.added, vec![("c".to_string(), "dijkstra".to_string())]);
+
+    let core = &comparison.changes[1];
+    assert_eq!((core.solver.as_str(), core.baseline_ms, core.current_ms), ("core", 2.0, 2.5));
+    assert!((core.slowdown() - 0.25).abs() < 1e-12);
+    assert!(comparison.changes[0].slowdown().abs() < 1e-12);
+
+    let regressions = comparison.regressions(0.1);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].solver, "core");
+    assert!(comparison.regressions(0.3).is_empty());
+
+    // Getting faster is never a regression
+    let faster = Comparison::new(&current, &baseline);
+    assert!(faster.regressions(0.0).is_empty());
+}
@@ -0,0 +1,117 @@
+use shortest_path_validation::graph::{Edge, Graph, WeightedGraph};
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::bellman_ford::{bellman_ford, find_negative_cycle};
+use shortest_path_validation::dispatch::{shortest_paths, Algorithm};
+
+#[test]
+fn test_negative_edges() {
+    // Dijkstra settles 2 at distance 2 before seeing 0 -> 1 -> 2 = 5 - 4 = 1
+    let mut graph = Graph::new(4);
+    graph.add_edge(0, 1, 5.0);
+    graph.add_edge(0, 2, 2.0);
+    graph.add_edge(1, 2, -4.0);
+    graph.add_edge(2, 3, 1.0);
+
+    assert!(graph.has_negative_weights());
+    assert_eq!(bellman_ford(&graph, 0).unwrap(), vec![0.0, 5.0, 1.0, 2.0]);
+}
+
+#[test]
+fn test_matches_dijkstra_on_non_negative_graphs() {
+    let graph = Graph::generate_random(200, 0.05, 100.0);
+    assert!(!graph.has_negative_weights());
+
+    let expected = dijkstra(&graph, 0);
+    let dist = bellman_ford(&graph, 0).unwrap();
+    for (i, (&d, &e)) in dist.iter().zip(&expected).enumerate() {
+        if e.is_finite() {
+            assert!((d - e).abs() < 1e-9, "Distance mismatch at vertex {}: {} vs {}", i, d, e);
+        } else {
+            assert!(d.is_infinite());
+        }
+    }
+}
+
+#[test]
+fn test_negative_cycle_detection() {
+    // 0 -> 1 -> 2 -> 3 -> 1 with cycle weight 2 + 3 - 6 = -1
+    let mut graph = Graph::new(5);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(1, 2, 2.0);
+    graph.add_edge(2, 3, 3.0);
+    graph.add_edge(3, 1, -6.0);
+    graph.add_edge(3, 4, 1.0);
+
+    let err = bellman_ford(&graph, 0).unwrap_err();
+    let mut cycle = err.cycle.clone();
+    cycle.sort_unstable();
+    assert_eq!(cycle, vec![1, 2, 3]);
+    assert_eq!(err.weight(&graph), -1.0);
+
+    // Edge order must be preserved
+    let k = err.cycle.len();
+    for i in 0..k {
+        let (u, v) = (err.cycle[i], err.cycle[(i + 1) % k]);
+        assert!(graph.edges[u].iter().any(|e| e.to == v), "{} -> {} is not an edge", u, v);
+    }
+}
+
+#[test]
+fn test_unreachable_negative_cycle() {
+    let mut graph = Graph::new(4);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(2, 3, -1.0);
+    graph.add_edge(3, 2, -1.0);
+
+    // Not reachable from 0, so single-source still succeeds
+    let dist = bellman_ford(&graph, 0).unwrap();
+    assert_eq!(dist[1], 1.0);
+    assert!(dist[2].is_infinite());
+
+    let cycle = find_negative_cycle(&graph).unwrap();
+    assert_eq!(cycle.weight(&graph), -2.0);
+
+    let mut self_loop = Graph::new(2);
+    self_loop.add_edge(1, 1, -0.5);
+    assert_eq!(find_negative_cycle(&self_loop).unwrap().cycle, vec![1]);
+}
+
+#[test]
+fn test_dispatch_routes_negative_graphs() {
+    let graph = Graph::generate_random(50, 0.1, 10.0);
+    let solution = shortest_paths(&graph, 0).unwrap();
+    assert_eq!(solution.algorithm, Algorithm::Dijkstra);
+
+    // The 2 -> 0 edge makes the graph cyclic, so the DAG solver does not apply
+    let mut negative = Graph::new(3);
+    negative.add_edge(0, 1, 2.0);
+    negative.add_edge(1, 2, -1.0);
+    negative.add_edge(2, 0, 5.0);
+    let solution = shortest_paths(&negative, 0).unwrap();
+    assert_eq!(solution.algorithm, Algorithm::BellmanFord);
+    assert_eq!(solution.dist, vec![0.0, 2.0, 1.0]);
+
+    negative.add_edge(2, 1, -1.0);
+    assert!(shortest_paths(&negative, 0).is_err());
+}
+
+#[test]
+fn test_negative_weights_detected_without_add_edge() {
+    // Edges pushed directly or given in a struct literal still count
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(2, 0, 5.0);
+    graph.edges[1].push(Edge { to: 2, weight: -3.0 });
+    assert!(graph.has_negative_weights());
+
+    let solution = shortest_paths(&graph, 0).unwrap();
+    assert_eq!(solution.algorithm, Algorithm::BellmanFord);
+    assert_eq!(solution.dist, vec![0.0, 1.0, -2.0]);
+
+    let graph = Graph {
+        n: 2,
+        edges: vec![vec![Edge { to: 1, weight: -1.0 }], vec![]],
+        coords: None,
+    };
+    assert!(graph.has_negative_weights());
+}
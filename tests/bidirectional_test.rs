@@ -0,0 +1,64 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::bidirectional::bidirectional_dijkstra;
+
+#[test]
+fn test_reverse_graph() {
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(1, 2, 2.0);
+
+    let reverse = graph.reverse();
+    assert_eq!(reverse.m(), 2);
+    assert_eq!(reverse.edges[1][0].to, 0);
+    assert_eq!(reverse.edges[2][0].to, 1);
+    assert_eq!(reverse.edges[2][0].weight, 2.0);
+    assert!(reverse.edges[0].is_empty());
+}
+
+#[test]
+fn test_stopping_criterion() {
+    // The first meeting point (vertex 1) is not on the shortest path:
+    // 0 -> 1 -> 3 costs 10, while 0 -> 2 -> 3 costs 6
+    let mut graph = Graph::new(4);
+    graph.add_edge(0, 1, 5.0);
+    graph.add_edge(1, 3, 5.0);
+    graph.add_edge(0, 2, 3.0);
+    graph.add_edge(2, 3, 3.0);
+
+    let reverse = graph.reverse();
+    assert_eq!(bidirectional_dijkstra(&graph, &reverse, 0, 3), 6.0);
+}
+
+#[test]
+fn test_unreachable_and_trivial() {
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1, 1.0);
+
+    let reverse = graph.reverse();
+    assert!(bidirectional_dijkstra(&graph, &reverse, 0, 2).is_infinite());
+    assert!(bidirectional_dijkstra(&graph, &reverse, 1, 0).is_infinite());
+    assert_eq!(bidirectional_dijkstra(&graph, &reverse, 2, 2), 0.0);
+}
+
+#[test]
+fn test_matches_dijkstra() {
+    for n in [10, 50, 200] {
+        let graph = Graph::generate_random(n, 0.1, 10.0);
+        let reverse = graph.reverse();
+
+        for s in 0..n.min(5) {
+            let expected = dijkstra(&graph, s);
+            for (t, &want) in expected.iter().enumerate() {
+                let d = bidirectional_dijkstra(&graph, &reverse, s, t);
+                if want.is_finite() {
+                    assert!((d - want).abs() < 1e-9,
+                            "Distance mismatch for {} -> {} in graph size {}: {} vs {}",
+                            s, t, n, d, want);
+                } else {
+                    assert!(d.is_infinite());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use shortest_path_validation::cli::{max_difference, parse_solvers, select_sources, GraphSpec, Options};
+use shortest_path_validation::solver::Solver;
+use std::path::PathBuf;
+
+fn args(line: &str) -> Vec<String> {
+    line.split_whitespace().map(String::from).collect()
+}
+
+#[test]
+fn test_options() {
+    let options = Options::parse(args("in.gr --seed 7 --source 1,2 --source=5 --verbose --reps 3"), &["verbose"]).unwrap();
+
+    assert_eq!(options.positional, vec!["in.gr"]);
+    assert_eq!(options.get("seed"), Some("7"));
+    assert_eq!(options.get_all("source"), vec!["1,2", "5"]);
+    assert_eq!(options.parse_list::<usize>("source").unwrap(), vec![1, 2, 5]);
+    assert_eq!(options.parse_or("reps", 1usize).unwrap(), 3);
+    assert_eq!(options.parse_or("warmup", 1usize).unwrap(), 1);
+    assert!(options.flag("verbose"));
+
+    assert!(options.check_known(&["seed", "source", "verbose", "reps"]).is_ok());
+    assert!(options.check_known(&["seed", "source", "verbose"]).is_err());
+    assert!(options.parse_or("seed", 0u8).is_ok());
+    assert!(Options::parse(args("--seed"), &[]).is_err());
+    assert!(Options::parse(args("--reps x"), &[]).unwrap().parse_or("reps", 1usize).is_err());
+}
+
+#[test]
+fn test_graph_specs() {
+    let spec: GraphSpec = "random:n=100,density=0.05".parse().unwrap();
+    assert_eq!(spec, GraphSpec::Random { n: 100, density: 0.05, max_weight: 100.0 });
+    assert_eq!(spec.to_string(), "random:n=100,density=0.05,max_weight=100");
+    assert_eq!(spec.to_string().parse::<GraphSpec>().unwrap(), spec);
+
+    let spec: GraphSpec = "file:path=road.gr,co=road.co".parse().unwrap();
+    assert_eq!(spec, GraphSpec::File { path: PathBuf::from("road.gr"), coords: Some(PathBuf::from("road.co")) });
+    assert_eq!("road.gr".parse::<GraphSpec>().unwrap(), GraphSpec::File { path: PathBuf::from("road.gr"), coords: None });
+
+    assert!("random:n=100".parse::<GraphSpec>().is_err());
+    assert!("random:n=100,density=x".parse::<GraphSpec>().is_err());
+    assert!("random:n=100,density=0.1,radius=2".parse::<GraphSpec>().is_err());
+    assert!("lattice:n=100".parse::<GraphSpec>().is_err());
+}
+
+#[test]
+fn test_seeded_graphs_are_reproducible() {
+    let spec: GraphSpec = "geometric:n=200,radius=0.1".parse().unwrap();
+    let a = spec.build(42).unwrap();
+    let b = spec.build(42).unwrap();
+    let c = spec.build(43).unwrap();
+
+    assert_eq!(a.coords, b.coords);
+    assert_eq!(a.m(), b.m());
+    assert!(a.edges.iter().flatten().zip(b.edges.iter().flatten()).all(|(x, y)| x.to == y.to && x.weight == y.weight));
+    assert_ne!(a.coords, c.coords);
+
+    let missing = GraphSpec::File { path: PathBuf::from("/nonexistent/graph.gr"), coords: None };
+    assert!(missing.build(0).is_err());
+
+    // .bin paths are read as binary CSR
+    let path = std::env::temp_dir().join(format!("cli_test_{}.bin", std::process::id()));
+    shortest_path_validation::mmap_graph::write_binary(&a, &path).unwrap();
+    let loaded = path.to_str().unwrap().parse::<GraphSpec>().unwrap().build(0);
+    std::fs::remove_file(&path).unwrap();
+    let loaded = loaded.unwrap();
+    assert_eq!((loaded.n, loaded.m()), (a.n, a.m()));
+}
+
+#[test]
+fn test_graph_families() {
+    let spec: GraphSpec = "grid:rows=4,cols=5".parse().unwrap();
+    assert_eq!(spec, GraphSpec::Grid { rows: 4, cols: 5, max_weight: 100.0 });
+    assert_eq!(spec.to_string().parse::<GraphSpec>().unwrap(), spec);
+    let grid = spec.build(1).unwrap();
+    assert_eq!(grid.n, 20);
+    // Edges both ways along 4 * 4 horizontal and 3 * 5 vertical links
+    assert_eq!(grid.m(), 2 * (4 * 4 + 3 * 5));
+    assert!(grid.edges[6].iter().all(|e| [1, 5, 7, 11].contains(&e.to)));
+    assert_eq!(grid.coords.as_ref().unwrap()[19], (1.0, 0.75));
+
+    let spec: GraphSpec = "scale-free:n=300,attach=3,max_weight=10".parse().unwrap();
+    assert_eq!(spec, GraphSpec::ScaleFree { n: 300, attach: 3, max_weight: 10.0 });
+    assert_eq!(spec.to_string().parse::<GraphSpec>().unwrap(), spec);
+    let graph = spec.build(1).unwrap();
+    assert_eq!(graph.n, 300);
+    // Clique on 4 vertices, then 3 links per added vertex, each both ways
+    assert_eq!(graph.m(), 2 * (6 + 296 * 3));
+    assert!(graph.edges.iter().all(|edges| edges.len() >= 3));
+    assert!(graph.edges.iter().flatten().all(|e| (0.0..10.0).contains(&e.weight)));
+    // Preferential attachment grows hubs well above the average degree
+    let max_degree = graph.edges.iter().map(Vec::len).max().unwrap();
+    assert!(max_degree > 5 * graph.m() / graph.n);
+
+    assert!("grid:rows=4".parse::<GraphSpec>().is_err());
+    assert!("scale-free:n=10,attach=2,radius=1".parse::<GraphSpec>().is_err());
+}
+
+#[test]
+fn test_sources() {
+    assert_eq!(select_sources(&[], 0, 10, 1).unwrap(), vec![0]);
+    assert_eq!(select_sources(&[3, 7], 5, 10, 1).unwrap(), vec![3, 7]);
+    assert!(select_sources(&[10], 0, 10, 1).is_err());
+
+    let random = select_sources(&[], 5, 10, 1).unwrap();
+    assert_eq!(random, select_sources(&[], 5, 10, 1).unwrap());
+    let mut distinct = random.clone();
+    distinct.sort_unstable();
+    distinct.dedup();
+    assert_eq!(distinct.len(), 5);
+    assert_eq!(select_sources(&[], 50, 10, 1).unwrap().len(), 10);
+}
+
+#[test]
+fn test_solver_selection() {
+    assert_eq!(parse_solvers(&["core", "dijkstra"]).unwrap(), vec![Solver::Core, Solver::Dijkstra]);
+    assert_eq!(parse_solvers(&["all"]).unwrap(), Solver::ALL.to_vec());
+    assert!(parse_solvers(&["quantum"]).is_err());
+}
+
+#[test]
+fn test_max_difference() {
+    let inf = f64::INFINITY;
+    assert_eq!(max_difference(&[0.0, 1.0, inf], &[0.0, 1.5, inf]), 0.5);
+    assert_eq!(max_difference(&[0.0, 1.0], &[0.0, inf]), inf);
+}
@@ -0,0 +1,138 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::contraction_hierarchy::ContractionHierarchy;
+
+fn assert_matches_dijkstra(graph: &Graph, ch: &ContractionHierarchy) {
+    for s in 0..graph.n.min(5) {
+        let expected = dijkstra(graph, s);
+        for (t, &want) in expected.iter().enumerate() {
+            let d = ch.query(s, t);
+            if want.is_finite() {
+                assert!((d - want).abs() < 1e-9, "Distance mismatch for {} -> {}: {} vs {}", s, t, d, want);
+            } else {
+                assert!(d.is_infinite());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_shortcut_on_path() {
+    // Contracting the middle of 0 -> 1 -> 2 needs a shortcut unless 1 goes last
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(1, 2, 2.0);
+
+    let ch = ContractionHierarchy::build(&graph);
+    assert_eq!(ch.query(0, 2), 3.0);
+    assert!(ch.query(2, 0).is_infinite());
+
+    let (d, path) = ch.query_path(0, 2).unwrap();
+    assert_eq!(d, 3.0);
+    assert_eq!(path, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_matches_dijkstra() {
+    let random = Graph::generate_random(150, 0.03, 10.0);
+    assert_matches_dijkstra(&random, &ContractionHierarchy::build(&random));
+
+    let geometric = Graph::generate_geometric(300, 0.12);
+    assert_matches_dijkstra(&geometric, &ContractionHierarchy::build(&geometric));
+}
+
+#[test]
+fn test_path_unpacking() {
+    let graph = Graph::generate_geometric(200, 0.15);
+    let ch = ContractionHierarchy::build(&graph);
+
+    for t in 0..graph.n {
+        let Some((d, path)) = ch.query_path(0, t) else {
+            assert!(ch.query(0, t).is_infinite());
+            continue;
+        };
+        assert_eq!(path.first(), Some(&0));
+        assert_eq!(path.last(), Some(&t));
+
+        // Every step must be an original edge and the weights must add up
+        let mut length = 0.0;
+        for pair in path.windows(2) {
+            let weight = graph.edges[pair[0]]
+                .iter()
+                .filter(|e| e.to == pair[1])
+                .map(|e| e.weight)
+                .fold(f64::INFINITY, f64::min);
+            assert!(weight.is_finite(), "{} -> {} is not an edge", pair[0], pair[1]);
+            length += weight;
+        }
+        assert!((length - d).abs() < 1e-9, "Path length {} differs from distance {}", length, d);
+    }
+}
+
+#[test]
+fn test_save_and_load() {
+    let graph = Graph::generate_geometric(150, 0.15);
+    let ch = ContractionHierarchy::build(&graph);
+
+    let path = std::env::temp_dir().join(format!("ch_test_{}.bin", std::process::id()));
+    ch.save(&path).unwrap();
+    let loaded = ContractionHierarchy::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.rank, ch.rank);
+    assert_eq!(loaded.up, ch.up);
+    assert_eq!(loaded.down, ch.down);
+    assert_eq!(loaded.shortcuts(), ch.shortcuts());
+    assert_matches_dijkstra(&graph, &loaded);
+}
+
+#[test]
+fn test_load_rejects_bad_headers() {
+    let mut graph = Graph::new(2);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(1, 0, 2.0);
+    let path = std::env::temp_dir().join(format!("ch_test_headers_{}.bin", std::process::id()));
+    ContractionHierarchy::build(&graph).save(&path).unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+
+    let load = |bytes: &[u8]| {
+        std::fs::write(&path, bytes).unwrap();
+        ContractionHierarchy::load(&path).map(|ch| ch.query(0, 1))
+    };
+    assert_eq!(load(&bytes).unwrap(), 1.0);
+
+    // Magic and n, two ranks, then the four edge lists
+    let first_list = (0..4)
+        .scan(28, |at, _| {
+            let list = *at;
+            let len = u64::from_le_bytes(bytes[list..list + 8].try_into().unwrap()) as usize;
+            *at += 8 + 24 * len;
+            Some((list, len))
+        })
+        .find(|&(_, len)| len > 0)
+        .unwrap()
+        .0;
+
+    let invalid = |bytes: &[u8]| load(bytes).unwrap_err().kind() == std::io::ErrorKind::InvalidData;
+
+    // Sizes larger than the file must fail before anything is allocated
+    let mut huge_n = bytes.clone();
+    huge_n[4..12].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(invalid(&huge_n));
+    let mut huge_list = bytes.clone();
+    huge_list[first_list..first_list + 8].copy_from_slice(&(u64::MAX / 2).to_le_bytes());
+    assert!(invalid(&huge_list));
+    assert!(load(&bytes[..bytes.len() - 8]).is_err());
+    assert!(invalid(&[&bytes[..], &[0; 8]].concat()));
+
+    let mut bad_rank = bytes.clone();
+    bad_rank[12..20].copy_from_slice(&2u64.to_le_bytes());
+    assert!(invalid(&bad_rank));
+
+    let mut bad_target = bytes.clone();
+    bad_target[first_list + 8..first_list + 16].copy_from_slice(&5u64.to_le_bytes());
+    let err = load(&bad_target).unwrap_err();
+    assert!(err.to_string().contains("edge target 5"), "{}", err);
+
+    std::fs::remove_file(&path).unwrap();
+}
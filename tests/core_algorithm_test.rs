@@ -1,6 +1,7 @@
 use shortest_path_validation::graph::Graph;
 use shortest_path_validation::dijkstra::dijkstra;
-use shortest_path_validation::core_algorithm::{CoreAlgorithm, PartialSortDS};
+use shortest_path_validation::core_algorithm::{CoreAlgorithm, CoreSolver, PartialSortDS};
+use std::collections::HashSet;
 
 #[test]
 fn test_parameter_calculation() {
@@ -206,4 +207,57 @@ fn test_complexity_scaling() {
         assert!(ratio < 2.0, 
                 "Normalized times should be stable, but ratio is {:.2}", ratio);
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_borrowed_solver() {
+    // One borrowed solver answers many sources on the same graph
+    let graph = Graph::generate_random(300, 0.03, 10.0);
+    let solver = CoreSolver::new(&graph);
+    let owned = CoreAlgorithm::new(graph.clone());
+    assert_eq!((solver.k, solver.t), owned.get_params());
+    assert_eq!((owned.solver().k, owned.solver().t), owned.get_params());
+    
+    for s in [0, 17, 150, 299] {
+        let dist = solver.sssp(s);
+        let expected = dijkstra(&graph, s);
+        for i in 0..graph.n {
+            if expected[i].is_finite() {
+                assert!((dist[i] - expected[i]).abs() < 1e-9,
+                        "Distance mismatch at vertex {} from {}: {} vs {}", i, s, dist[i], expected[i]);
+            } else {
+                assert!(dist[i].is_infinite(), "Vertex {} should be unreachable from {}", i, s);
+            }
+        }
+        assert_eq!(dist, owned.solver().sssp(s));
+    }
+}
+
+#[test]
+fn test_step_methods_match_solver() {
+    // The step methods run the solver's code, so a top-level bmssp from one
+    // source reproduces sssp exactly
+    let graph = Graph::generate_random(400, 0.02, 10.0);
+    let solver = CoreSolver::new(&graph);
+    let l = (((graph.n as f64).ln() / solver.t as f64).ceil() as usize).max(1);
+    
+    for s in [0, 123] {
+        let mut algo = CoreAlgorithm::new(graph.clone());
+        algo.dist[s] = 0.0;
+        algo.bmssp(l, f64::INFINITY, [s].into_iter().collect());
+        assert_eq!(algo.dist, solver.sssp(s));
+    }
+    
+    // Repeated pivot searches from the same state agree, whatever the
+    // hash order of the sources
+    let mut algo = CoreAlgorithm::new(graph.clone());
+    for v in 0..40 {
+        algo.dist[v] = v as f64 / 10.0;
+    }
+    let mut again = CoreAlgorithm::new(graph);
+    again.dist.clone_from(&algo.dist);
+    let forward: HashSet<usize> = (0..40).collect();
+    let backward: HashSet<usize> = (0..40).rev().collect();
+    assert_eq!(algo.find_pivots(20.0, &forward), again.find_pivots(20.0, &backward));
+    assert_eq!(algo.dist, again.dist);
+}
@@ -0,0 +1,74 @@
+use shortest_path_validation::counting_allocator::{is_installed, measure, CountingAllocator};
+use shortest_path_validation::core_algorithm::{CoreAlgorithm, CoreSolver, CoreWorkspace};
+use shortest_path_validation::dijkstra::DijkstraWorkspace;
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::solver::Solver;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// One test, since the counters are global and the harness runs tests
+// concurrently
+#[test]
+fn test_counting() {
+    assert!(is_installed());
+
+    let (v, stats) = measure(|| vec![0u64; 1000]);
+    assert_eq!(v.len(), 1000);
+    assert!(stats.allocations >= 1);
+    assert!(stats.allocated_bytes >= 8000);
+    assert!(stats.peak_bytes >= 8000);
+
+    // Freed memory still counts towards the total but not the peak
+    let ((), stats) = measure(|| {
+        for _ in 0..10 {
+            drop(std::hint::black_box(vec![0u8; 4096]));
+        }
+    });
+    assert!(stats.allocations >= 10);
+    assert!(stats.allocated_bytes >= 40960);
+    assert!(stats.peak_bytes >= 4096 && stats.peak_bytes < 40960);
+
+    // Growth by reallocation
+    let (v, stats) = measure(|| {
+        let mut v = Vec::new();
+        for i in 0..10_000u32 {
+            v.push(i);
+        }
+        v
+    });
+    assert!(stats.allocations > 1);
+    assert!(stats.peak_bytes >= 4 * v.len());
+
+    // The owning core algorithm copies the graph, the borrowing one does not
+    let graph = Graph::generate_random(200, 0.1, 100.0);
+    let edge_bytes = graph.m() * std::mem::size_of_val(&graph.edges[0][0]);
+    let (_, owned) = measure(|| CoreAlgorithm::new(graph.clone()).sssp(0));
+    let (_, borrowed) = measure(|| Solver::Core.run(&graph, 0));
+    assert!(owned.allocated_bytes >= borrowed.allocated_bytes + edge_bytes as u64);
+    assert!(owned.allocations > borrowed.allocations + graph.n as u64);
+
+    // Workspaces stop allocating once their buffers have grown
+    let mut workspace = DijkstraWorkspace::new(graph.n);
+    for s in 0..5 {
+        workspace.run(&graph, s);
+    }
+    let ((), stats) = measure(|| {
+        for s in 0..20 {
+            workspace.run(&graph, s % 5);
+        }
+    });
+    assert_eq!(stats.allocations, 0);
+
+    let algo = CoreSolver::new(&graph);
+    let mut workspace = CoreWorkspace::new(graph.n);
+    for s in 0..5 {
+        workspace.run(&algo, s);
+    }
+    let ((), stats) = measure(|| {
+        for s in 0..20 {
+            workspace.run(&algo, s % 5);
+        }
+    });
+    assert_eq!(stats.allocations, 0);
+}
@@ -0,0 +1,101 @@
+use shortest_path_validation::graph::{Graph, WeightedGraph};
+use shortest_path_validation::bellman_ford::bellman_ford;
+use shortest_path_validation::dag::dag_shortest_paths;
+use shortest_path_validation::dispatch::{choose_algorithm, shortest_paths, Algorithm};
+
+/// Random DAG: edges only go from lower to higher ids, some negative
+fn random_dag(n: usize, density: f64) -> Graph {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let mut graph = Graph::new(n);
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if rng.gen::<f64>() < density {
+                graph.add_edge(i, j, rng.gen::<f64>() * 20.0 - 5.0);
+            }
+        }
+    }
+    graph
+}
+
+#[test]
+fn test_topological_order() {
+    let mut graph = Graph::new(5);
+    graph.add_edge(3, 1, 1.0);
+    graph.add_edge(1, 0, 1.0);
+    graph.add_edge(3, 4, 1.0);
+    graph.add_edge(4, 0, 1.0);
+    graph.add_edge(2, 4, 1.0);
+
+    let order = graph.topological_order().unwrap();
+    let mut position = vec![0; graph.n];
+    for (i, &v) in order.iter().enumerate() {
+        position[v] = i;
+    }
+    for (u, edges) in graph.edges.iter().enumerate() {
+        for edge in edges {
+            assert!(position[u] < position[edge.to], "edge {} -> {} goes backwards", u, edge.to);
+        }
+    }
+    assert!(graph.is_acyclic());
+    assert!(graph.find_cycle().is_none());
+}
+
+#[test]
+fn test_find_cycle() {
+    let mut graph = Graph::new(5);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(1, 2, 1.0);
+    graph.add_edge(2, 3, 1.0);
+    graph.add_edge(3, 1, 1.0);
+    graph.add_edge(3, 4, 1.0);
+
+    assert!(graph.topological_order().is_none());
+    let cycle = graph.find_cycle().unwrap();
+    assert_eq!(cycle.len(), 3);
+    for (i, &u) in cycle.iter().enumerate() {
+        let v = cycle[(i + 1) % cycle.len()];
+        assert!(graph.edges[u].iter().any(|e| e.to == v), "missing cycle edge {} -> {}", u, v);
+    }
+
+    let mut self_loop = Graph::new(2);
+    self_loop.add_edge(1, 1, 1.0);
+    assert_eq!(self_loop.find_cycle(), Some(vec![1]));
+}
+
+#[test]
+fn test_matches_bellman_ford_with_negative_weights() {
+    let graph = random_dag(200, 0.05);
+    assert!(graph.has_negative_weights());
+
+    for source in [0, 17, 199] {
+        let expected = bellman_ford(&graph, source).unwrap();
+        let dist = dag_shortest_paths(&graph, source).unwrap();
+        for (i, (&d, &e)) in dist.iter().zip(&expected).enumerate() {
+            if e.is_finite() {
+                assert!((d - e).abs() < 1e-9, "Distance mismatch at vertex {}: {} vs {}", i, d, e);
+            } else {
+                assert!(d.is_infinite());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_cyclic_graph_is_rejected() {
+    let mut graph = Graph::new(2);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(1, 0, 1.0);
+    assert!(dag_shortest_paths(&graph, 0).is_none());
+}
+
+#[test]
+fn test_dispatch_picks_dag() {
+    let graph = random_dag(100, 0.1);
+    assert_eq!(choose_algorithm(&graph), Algorithm::Dag);
+
+    let solution = shortest_paths(&graph, 0).unwrap();
+    assert_eq!(solution.algorithm, Algorithm::Dag);
+    assert_eq!(solution.algorithm.name(), "dag");
+    assert_eq!(solution.dist, dag_shortest_paths(&graph, 0).unwrap());
+}
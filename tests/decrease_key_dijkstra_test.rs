@@ -0,0 +1,72 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::addressable_heap::{AddressableQueue, DaryHeap, FibonacciHeap, PairingHeap};
+use shortest_path_validation::decrease_key_dijkstra::{dary_dijkstra, fibonacci_dijkstra, pairing_dijkstra};
+
+/// Random inserts, decrease-keys and pops checked against a sorted reference
+fn exercise<H: AddressableQueue>() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    let n = 500;
+    let mut heap = H::with_capacity(n);
+    let mut keys = vec![f64::INFINITY; n];
+    let mut popped = vec![false; n];
+    let mut last = f64::NEG_INFINITY;
+
+    for (item, key) in keys.iter_mut().enumerate() {
+        *key = rng.gen::<f64>() * 1000.0;
+        heap.insert(item, *key);
+    }
+
+    while !heap.is_empty() {
+        for _ in 0..3 {
+            let item = rng.gen_range(0..n);
+            if heap.contains(item) {
+                // Stay above the last popped key so pops remain monotone
+                let key = last.max(0.0) + (keys[item] - last.max(0.0)) * rng.gen::<f64>();
+                keys[item] = key;
+                heap.decrease_key(item, key);
+            }
+        }
+
+        let (item, key) = heap.pop_min().unwrap();
+        assert_eq!(key, keys[item]);
+        assert!(!popped[item] && !heap.contains(item));
+        let expected_min = (0..n).filter(|&v| !popped[v]).map(|v| keys[v]).fold(f64::INFINITY, f64::min);
+        assert_eq!(key, expected_min);
+        assert!(key >= last);
+        popped[item] = true;
+        last = key;
+    }
+
+    assert!(heap.pop_min().is_none());
+}
+
+#[test]
+fn test_dary_heap_operations() {
+    exercise::<DaryHeap<2>>();
+    exercise::<DaryHeap<4>>();
+    exercise::<DaryHeap<8>>();
+}
+
+#[test]
+fn test_pairing_heap_operations() {
+    exercise::<PairingHeap>();
+}
+
+#[test]
+fn test_fibonacci_heap_operations() {
+    exercise::<FibonacciHeap>();
+}
+
+#[test]
+fn test_matches_dijkstra() {
+    for n in [10, 100, 500] {
+        let graph = Graph::generate_random(n, 0.05, 100.0);
+        let expected = dijkstra(&graph, 0);
+
+        assert_eq!(fibonacci_dijkstra(&graph, 0), expected, "Fibonacci heap, n={}", n);
+        assert_eq!(pairing_dijkstra(&graph, 0), expected, "pairing heap, n={}", n);
+        assert_eq!(dary_dijkstra(&graph, 0), expected, "4-ary heap, n={}", n);
+    }
+}
@@ -0,0 +1,116 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::core_algorithm::CoreAlgorithm;
+use shortest_path_validation::delta_stepping::{auto_delta, delta_stepping, delta_stepping_with_delta, EdgeSplit};
+
+fn assert_same(actual: &[f64], expected: &[f64]) {
+    for (i, (&a, &e)) in actual.iter().zip(expected).enumerate() {
+        if e.is_finite() {
+            assert!((a - e).abs() < 1e-9, "Distance mismatch at vertex {}: {} vs {}", i, a, e);
+        } else {
+            assert!(a.is_infinite(), "Vertex {} should be unreachable", i);
+        }
+    }
+}
+
+#[test]
+fn test_edge_split() {
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(0, 2, 5.0);
+    graph.add_edge(1, 2, 2.0);
+
+    let split = EdgeSplit::new(&graph, 2.0);
+    assert_eq!(split.light[0], vec![(1, 1.0)]);
+    assert_eq!(split.heavy[0], vec![(2, 5.0)]);
+    assert_eq!(split.light[1], vec![(2, 2.0)]);
+    assert_eq!(split.max_weight, 5.0);
+}
+
+#[test]
+fn test_auto_delta() {
+    let mut graph = Graph::new(2);
+    assert_eq!(auto_delta(&graph), 1.0);
+
+    // Only zero weights
+    let mut zero = Graph::new(2);
+    zero.add_edge(0, 1, 0.0);
+    assert_eq!(auto_delta(&zero), 1.0);
+
+    graph.add_edge(0, 1, 8.0);
+    graph.add_edge(1, 0, 4.0);
+    // max weight 8, average degree 1
+    assert_eq!(auto_delta(&graph), 8.0);
+}
+
+#[test]
+fn test_matches_dijkstra_for_various_deltas() {
+    let graph = Graph::generate_random(200, 0.05, 100.0);
+    let expected = dijkstra(&graph, 0);
+
+    for delta in [0.5, 5.0, 25.0, 100.0, 1000.0] {
+        assert_same(&delta_stepping_with_delta(&graph, 0, delta), &expected);
+    }
+    assert_same(&delta_stepping(&graph, 0), &expected);
+}
+
+#[test]
+fn test_matches_core_algorithm() {
+    for n in [10, 50, 100] {
+        let graph = Graph::generate_random(n, 0.2, 10.0);
+        let core_dist = CoreAlgorithm::new(graph.clone()).sssp(0);
+        let delta_dist = delta_stepping(&graph, 0);
+
+        for i in 0..n {
+            if core_dist[i].is_finite() {
+                assert!((core_dist[i] - delta_dist[i]).abs() < 1e-6,
+                        "Distance mismatch at vertex {} in graph size {}: {} vs {}",
+                        i, n, core_dist[i], delta_dist[i]);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_zero_weight_edges() {
+    let mut graph = Graph::new(4);
+    graph.add_edge(0, 1, 0.0);
+    graph.add_edge(1, 2, 0.0);
+    graph.add_edge(2, 3, 3.0);
+
+    assert_eq!(delta_stepping(&graph, 0), vec![0.0, 0.0, 0.0, 3.0]);
+}
+
+#[test]
+fn test_tiny_delta_is_raised() {
+    let graph = Graph::generate_random(50, 0.2, 100.0);
+    let split = EdgeSplit::new(&graph, 1e-300);
+    assert!(split.max_weight / split.delta <= 51.0);
+    assert_same(&delta_stepping_with_delta(&graph, 0, 1e-300), &dijkstra(&graph, 0));
+    assert_same(&delta_stepping_with_delta(&graph, 0, f64::INFINITY), &dijkstra(&graph, 0));
+}
+
+#[test]
+#[should_panic(expected = "delta must be positive")]
+fn test_nan_delta_rejected() {
+    let mut graph = Graph::new(2);
+    graph.add_edge(0, 1, 1.0);
+    delta_stepping_with_delta(&graph, 0, f64::NAN);
+}
+
+#[test]
+#[should_panic(expected = "finite non-negative weights")]
+fn test_negative_weight_rejected() {
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1, 2.0);
+    graph.add_edge(1, 2, -1.0);
+    delta_stepping(&graph, 0);
+}
+
+#[test]
+#[should_panic(expected = "finite non-negative weights")]
+fn test_infinite_weight_rejected() {
+    let mut graph = Graph::new(2);
+    graph.add_edge(0, 1, f64::INFINITY);
+    delta_stepping_with_delta(&graph, 0, 1.0);
+}
@@ -0,0 +1,50 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::dimacs::{format_coordinates, format_graph, parse_coordinates, parse_graph};
+use std::io::Cursor;
+
+#[test]
+fn test_parse_graph() {
+    let input = "c sample graph\np sp 3 3\na 1 2 4\na 2 3 5\na 1 3 10\n";
+    let graph = parse_graph(Cursor::new(input)).unwrap();
+
+    assert_eq!(graph.n, 3);
+    assert_eq!(graph.m(), 3);
+    assert_eq!(graph.edges[0][0].to, 1);
+    assert_eq!(graph.edges[0][0].weight, 4.0);
+    assert_eq!(graph.edges[1][0].to, 2);
+}
+
+#[test]
+fn test_parse_coordinates() {
+    let input = "c sample coordinates\np aux sp co 2\nv 1 -73530767 41085396\nv 2 -73530538 41086098\n";
+    let coords = parse_coordinates(Cursor::new(input)).unwrap();
+
+    assert_eq!(coords, vec![(-73530767.0, 41085396.0), (-73530538.0, 41086098.0)]);
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!(parse_graph(Cursor::new("a 1 2 3\n")).is_err());
+    assert!(parse_graph(Cursor::new("p sp 2 1\na 1 3 1\n")).is_err());
+    assert!(parse_graph(Cursor::new("p sp 2 1\na 1 x 1\n")).is_err());
+    assert!(parse_coordinates(Cursor::new("c nothing\n")).is_err());
+}
+
+#[test]
+fn test_write_round_trip() {
+    let graph = Graph::generate_geometric(50, 0.3);
+
+    let mut gr = Vec::new();
+    format_graph(&graph, &mut gr).unwrap();
+    let read = parse_graph(Cursor::new(gr)).unwrap();
+    assert_eq!(read.n, graph.n);
+    for (a, b) in read.edges.iter().zip(&graph.edges) {
+        assert!(a.iter().zip(b).all(|(x, y)| x.to == y.to && x.weight == y.weight));
+        assert_eq!(a.len(), b.len());
+    }
+
+    let coords = graph.coords.unwrap();
+    let mut co = Vec::new();
+    format_coordinates(&coords, &mut co).unwrap();
+    assert_eq!(parse_coordinates(Cursor::new(co)).unwrap(), coords);
+}
@@ -0,0 +1,142 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::floyd_warshall::FloydWarshall;
+use shortest_path_validation::bellman_ford::{bellman_ford, find_negative_cycle};
+use shortest_path_validation::bidirectional::bidirectional_dijkstra;
+use shortest_path_validation::contraction_hierarchy::ContractionHierarchy;
+use shortest_path_validation::integer_dijkstra::{dial_dijkstra, radix_heap_dijkstra, IntegerGraph};
+use shortest_path_validation::johnson::Johnson;
+use shortest_path_validation::solver::Solver;
+
+fn assert_same(actual: &[f64], expected: &[f64], context: &str) {
+    assert_eq!(actual.len(), expected.len());
+    for (i, (&a, &e)) in actual.iter().zip(expected).enumerate() {
+        if e.is_finite() {
+            assert!((a - e).abs() < 1e-9, "{}: mismatch at vertex {}: {} vs {}", context, i, a, e);
+        } else {
+            assert!(a.is_infinite(), "{}: vertex {} should be unreachable, got {}", context, i, a);
+        }
+    }
+}
+
+/// Calls `f` with every simple directed graph on `n` vertices whose edges
+/// take a weight from `weights` (each ordered pair u != v is either absent
+/// or has one of the weights)
+fn for_each_graph<F: FnMut(&Graph)>(n: usize, weights: &[f64], mut f: F) {
+    let pairs: Vec<(usize, usize)> = (0..n)
+        .flat_map(|u| (0..n).filter(move |&v| v != u).map(move |v| (u, v)))
+        .collect();
+    let choices = weights.len() + 1;
+    let total = choices.pow(pairs.len() as u32);
+
+    for mut code in 0..total {
+        let mut graph = Graph::new(n);
+        for &(u, v) in &pairs {
+            let choice = code % choices;
+            code /= choices;
+            if choice > 0 {
+                graph.add_edge(u, v, weights[choice - 1]);
+            }
+        }
+        f(&graph);
+    }
+}
+
+/// Lightest u -> v edge
+fn edge_weight(graph: &Graph, u: usize, v: usize) -> f64 {
+    graph.edges[u]
+        .iter()
+        .filter(|edge| edge.to == v)
+        .map(|edge| edge.weight)
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn check_all_solvers(graph: &Graph) {
+    let oracle = FloydWarshall::new(graph);
+    let reverse = graph.reverse();
+    let ch = ContractionHierarchy::build(graph);
+    let integer = IntegerGraph::exact(graph).expect("integer weights");
+
+    for s in 0..graph.n {
+        let expected = &oracle.dist[s];
+        let context = format!("{:?} from {}", graph.edges, s);
+
+        for &solver in Solver::ALL {
+            assert_same(&solver.run(graph, s), expected, &format!("{} on {}", solver.name(), context));
+        }
+        assert_same(&bellman_ford(graph, s).unwrap(), expected, &context);
+        assert_same(&radix_heap_dijkstra(&integer, s), expected, &context);
+        assert_same(&dial_dijkstra(&integer, s), expected, &context);
+
+        let p2p: Vec<f64> = (0..graph.n).map(|t| bidirectional_dijkstra(graph, &reverse, s, t)).collect();
+        assert_same(&p2p, expected, &format!("bidirectional on {}", context));
+        let p2p: Vec<f64> = (0..graph.n).map(|t| ch.query(s, t)).collect();
+        assert_same(&p2p, expected, &format!("contraction hierarchy on {}", context));
+    }
+}
+
+#[test]
+fn test_known_graph_with_paths() {
+    let mut graph = Graph::new(4);
+    graph.add_edge(0, 1, 4.0);
+    graph.add_edge(0, 2, 1.0);
+    graph.add_edge(2, 1, 2.0);
+    graph.add_edge(1, 3, 1.0);
+
+    let fw = FloydWarshall::new(&graph);
+    assert_eq!(fw.dist[0], vec![0.0, 3.0, 1.0, 4.0]);
+    assert_eq!(fw.path(0, 3), Some(vec![0, 2, 1, 3]));
+    assert_eq!(fw.path(2, 2), Some(vec![2]));
+    assert_eq!(fw.path(3, 0), None);
+    assert!(fw.dist[3][0].is_infinite());
+    assert!(!fw.has_negative_cycle());
+}
+
+#[test]
+fn test_paths_realize_distances() {
+    let graph = Graph::generate_random(40, 0.1, 100.0);
+    let fw = FloydWarshall::new(&graph);
+
+    for s in 0..graph.n {
+        for t in 0..graph.n {
+            match fw.path(s, t) {
+                Some(path) => {
+                    let length: f64 = path.windows(2).map(|e| edge_weight(&graph, e[0], e[1])).sum();
+                    assert!((length - fw.dist[s][t]).abs() < 1e-9);
+                }
+                None => assert!(fw.dist[s][t].is_infinite()),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_exhaustive_small_graphs() {
+    // Zero weights exercise ties; n = 4 is limited to unit weights to keep
+    // the count at 2^12 graphs
+    for n in 1..=3 {
+        for_each_graph(n, &[0.0, 1.0, 2.0], check_all_solvers);
+    }
+    for_each_graph(4, &[1.0], check_all_solvers);
+}
+
+#[test]
+fn test_exhaustive_negative_weights() {
+    for n in 1..=3 {
+        for_each_graph(n, &[-1.0, 1.0, 2.0], |graph| {
+            let fw = FloydWarshall::new(graph);
+            let cycle = find_negative_cycle(graph);
+            assert_eq!(cycle.is_some(), fw.has_negative_cycle(), "{:?}", graph.edges);
+
+            match Johnson::new(graph, Solver::Dijkstra) {
+                Ok(johnson) => {
+                    for s in 0..graph.n {
+                        let context = format!("{:?} from {}", graph.edges, s);
+                        assert_same(&bellman_ford(graph, s).unwrap(), &fw.dist[s], &context);
+                        assert_same(&johnson.row(s), &fw.dist[s], &context);
+                    }
+                }
+                Err(cycle) => assert!(cycle.weight(graph) < 0.0),
+            }
+        });
+    }
+}
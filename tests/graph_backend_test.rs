@@ -0,0 +1,163 @@
+use shortest_path_validation::graph::{Graph, WeightedGraph};
+use shortest_path_validation::alt::{alt, LandmarkSelection, Landmarks};
+use shortest_path_validation::astar::{astar, ZeroHeuristic};
+use shortest_path_validation::bellman_ford::bellman_ford;
+use shortest_path_validation::bidirectional::bidirectional_dijkstra;
+use shortest_path_validation::contraction_hierarchy::ContractionHierarchy;
+use shortest_path_validation::csr::CsrGraph;
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::integer_dijkstra::{radix_heap_dijkstra, IntegerGraph};
+use shortest_path_validation::johnson::Johnson;
+use shortest_path_validation::reachability::pruned_sssp;
+use shortest_path_validation::implicit::{CompleteGraph, GridGraph};
+use shortest_path_validation::mmap_graph::{format_binary, write_binary, MmapGraph};
+use shortest_path_validation::scc::{kosaraju_scc, tarjan_scc};
+use shortest_path_validation::solver::Solver;
+use std::path::PathBuf;
+
+fn assert_same(actual: &[f64], expected: &[f64], context: &str) {
+    assert_eq!(actual.len(), expected.len());
+    for (i, (&a, &e)) in actual.iter().zip(expected).enumerate() {
+        if e.is_finite() {
+            assert!((a - e).abs() < 1e-9, "{}: mismatch at vertex {}: {} vs {}", context, i, a, e);
+        } else {
+            assert!(a.is_infinite(), "{}: vertex {} should be unreachable, got {}", context, i, a);
+        }
+    }
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("graph_backend_test_{}_{}.bin", name, std::process::id()))
+}
+
+/// Every registry solver on `view` must match Dijkstra on its adjacency-list copy
+fn assert_solvers_agree<G: WeightedGraph + Sync>(view: &G, backend: &str) {
+    let graph = Graph::from_view(view);
+    assert_eq!(graph.n, view.vertex_count());
+    assert_eq!(graph.m(), view.edge_count());
+
+    for source in [0, view.vertex_count() / 2] {
+        let expected = dijkstra(&graph, source);
+        for solver in Solver::ALL {
+            let context = format!("{} on {} from {}", solver.name(), backend, source);
+            assert_same(&solver.run_on(view, source), &expected, &context);
+        }
+    }
+}
+
+#[test]
+fn test_stored_backends() {
+    let graph = Graph::generate_random(150, 0.04, 100.0);
+    let csr = CsrGraph::from_graph(&graph);
+    let path = temp_path("stored");
+    write_binary(&graph, &path).unwrap();
+    let mapped = MmapGraph::open(&path).unwrap();
+
+    assert_solvers_agree(&graph, "graph");
+    assert_solvers_agree(&csr, "csr");
+    assert_solvers_agree(&mapped, "mmap");
+
+    for u in 0..graph.n {
+        let edges: Vec<(usize, f64)> = graph.out_neighbors(u).collect();
+        assert_eq!(mapped.out_neighbors(u).collect::<Vec<_>>(), edges);
+        assert_eq!(csr.out_neighbors(u).collect::<Vec<_>>(), edges);
+        assert_eq!(mapped.out_degree(u), edges.len());
+    }
+    drop(mapped);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_implicit_backends() {
+    let grid = GridGraph::new(12, 9, 50.0, 7);
+    assert_eq!(grid.vertex_count(), 108);
+    assert_eq!(grid.edge_count(), (0..108).map(|u| grid.out_neighbors(u).count()).sum::<usize>());
+    assert_solvers_agree(&grid, "grid");
+    assert_solvers_agree(&CompleteGraph::new(40, 10.0, 3), "complete");
+
+    // Weights are fixed per ordered pair and in-edges mirror out-edges
+    let again = GridGraph::new(12, 9, 50.0, 7);
+    for u in 0..grid.vertex_count() {
+        assert_eq!(grid.out_neighbors(u).collect::<Vec<_>>(), again.out_neighbors(u).collect::<Vec<_>>());
+        for (v, w) in grid.in_neighbors(u).unwrap() {
+            assert!(grid.out_neighbors(v).any(|(to, weight)| to == u && weight == w));
+        }
+    }
+    assert!(Graph::new(3).in_neighbors(0).is_none());
+
+    // The same components whichever way the DFS reads the graph
+    let grid_copy = Graph::from_view(&grid);
+    assert_eq!(tarjan_scc(&grid).count, 1);
+    assert_eq!(kosaraju_scc(&grid).component, kosaraju_scc(&grid_copy).component);
+}
+
+#[test]
+fn test_other_algorithms_on_views() {
+    let grid = GridGraph::new(8, 7, 20.0, 5);
+    let graph = Graph::from_view(&grid);
+    let reverse = graph.reverse();
+    let expected = dijkstra(&graph, 3);
+
+    let landmarks = Landmarks::build(&grid, 3, LandmarkSelection::Farthest);
+    let hierarchy = ContractionHierarchy::build(&grid);
+    for target in [0, 20, 55] {
+        let context = format!("grid 3 -> {}", target);
+        assert_same(&[astar(&grid, 3, target, &ZeroHeuristic)], &expected[target..=target], &context);
+        assert_same(&[alt(&grid, &landmarks, 3, target).unwrap()], &expected[target..=target], &context);
+        assert_same(&[bidirectional_dijkstra(&grid, &reverse, 3, target)], &expected[target..=target], &context);
+        assert_same(&[hierarchy.query(3, target)], &expected[target..=target], &context);
+    }
+
+    assert_same(&bellman_ford(&grid, 3).unwrap(), &expected, "bellman-ford on grid");
+    assert_same(&Johnson::new(&grid, Solver::Dijkstra).unwrap().row(3), &expected, "johnson on grid");
+    assert_same(&pruned_sssp(&grid, 3, Solver::Dijkstra).0, &expected, "pruned on grid");
+
+    // Quantizing a view rounds the same weights as quantizing its copy
+    let complete = CompleteGraph::new(30, 10.0, 9);
+    let expected = radix_heap_dijkstra(&IntegerGraph::quantize(&Graph::from_view(&complete), 4.0), 0);
+    assert_same(&radix_heap_dijkstra(&IntegerGraph::quantize(&complete, 4.0), 0), &expected, "radix heap on complete");
+}
+
+#[test]
+fn test_binary_file_validation() {
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1, 1.5);
+    graph.add_edge(1, 2, 2.5);
+    let mut bytes = Vec::new();
+    format_binary(&graph, &mut bytes).unwrap();
+    assert_eq!(bytes.len(), 24 + 8 * (4 + 2 + 2));
+
+    let open = |name: &str, bytes: &[u8]| {
+        let path = temp_path(name);
+        std::fs::write(&path, bytes).unwrap();
+        let result = MmapGraph::open(&path).map(|g| Graph::from_view(&g).m());
+        std::fs::remove_file(&path).unwrap();
+        result
+    };
+    assert_eq!(open("valid", &bytes).unwrap(), 2);
+
+    let mut bad_magic = bytes.clone();
+    bad_magic[0] = b'X';
+    assert!(open("magic", &bad_magic).is_err());
+    assert!(open("truncated", &bytes[..bytes.len() - 8]).is_err());
+
+    // Target of the second edge (byte 24 + 8 * 5) pointing past n
+    let mut bad_target = bytes.clone();
+    bad_target[64..72].copy_from_slice(&7u64.to_le_bytes());
+    let err = open("target", &bad_target).unwrap_err();
+    assert!(err.to_string().contains("targets vertex 7"), "{}", err);
+
+    // offsets[1] > offsets[2]
+    let mut bad_offsets = bytes.clone();
+    bad_offsets[32..40].copy_from_slice(&2u64.to_le_bytes());
+    bad_offsets[40..48].copy_from_slice(&1u64.to_le_bytes());
+    assert!(open("offsets", &bad_offsets).is_err());
+
+    // Header sizes that overflow when computing the expected length
+    let mut huge_n = bytes.clone();
+    huge_n[8..16].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(open("huge_n", &huge_n).is_err());
+    let mut huge_m = bytes.clone();
+    huge_m[16..24].copy_from_slice(&(u64::MAX / 2).to_le_bytes());
+    assert!(open("huge_m", &huge_m).is_err());
+}
@@ -0,0 +1,129 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::monotone_queue::{BinaryHeapQueue, DialQueue, MonotoneQueue, RadixHeap, MAX_DIAL_STEP};
+use shortest_path_validation::integer_dijkstra::{dial_dijkstra, dijkstra_with_queue, radix_heap_dijkstra, IntegerGraph};
+
+fn integer_graph(n: usize, density: f64, max_weight: f64) -> Graph {
+    let mut graph = Graph::generate_random(n, density, max_weight);
+    for edges in &mut graph.edges {
+        for edge in edges {
+            edge.weight = edge.weight.floor();
+        }
+    }
+    graph
+}
+
+fn drain<Q: MonotoneQueue>(max_step: u64, keys: &[u64]) -> Vec<u64> {
+    let mut queue = Q::with_max_step(max_step);
+    for (i, &key) in keys.iter().enumerate() {
+        queue.push(key, i);
+    }
+    let mut popped = Vec::new();
+    while let Some((key, _)) = queue.pop() {
+        popped.push(key);
+    }
+    assert!(queue.is_empty());
+    popped
+}
+
+#[test]
+fn test_queues_pop_in_order() {
+    let keys = [7, 3, 9, 3, 0, 12, 5];
+    let mut sorted = keys.to_vec();
+    sorted.sort_unstable();
+
+    assert_eq!(drain::<BinaryHeapQueue>(12, &keys), sorted);
+    assert_eq!(drain::<RadixHeap>(12, &keys), sorted);
+    assert_eq!(drain::<DialQueue>(12, &keys), sorted);
+}
+
+#[test]
+fn test_monotone_interleaving() {
+    // Push keys relative to the last popped one, as Dijkstra does
+    let mut radix = RadixHeap::with_max_step(10);
+    let mut dial = DialQueue::with_max_step(10);
+    radix.push(0, 0);
+    dial.push(0, 0);
+
+    let mut last = 0;
+    for step in 0..50u64 {
+        let (r, _) = radix.pop().unwrap();
+        let (d, _) = dial.pop().unwrap();
+        assert_eq!(r, d);
+        assert!(r >= last);
+        last = r;
+        for offset in [step % 7, (step * 3) % 11 % 10] {
+            radix.push(last + offset, 0);
+            dial.push(last + offset, 0);
+        }
+    }
+}
+
+#[test]
+fn test_exact_conversion() {
+    let mut graph = Graph::new(2);
+    graph.add_edge(0, 1, 3.0);
+    assert_eq!(IntegerGraph::exact(&graph).unwrap().max_weight, 3);
+
+    graph.add_edge(1, 0, 2.5);
+    assert!(IntegerGraph::exact(&graph).is_none());
+}
+
+#[test]
+fn test_integer_weights_match_dijkstra() {
+    let graph = integer_graph(300, 0.03, 100.0);
+    let integer = IntegerGraph::exact(&graph).unwrap();
+    let expected = dijkstra(&graph, 0);
+
+    assert_eq!(dijkstra_with_queue::<BinaryHeapQueue>(&integer, 0), expected);
+    assert_eq!(radix_heap_dijkstra(&integer, 0), expected);
+    assert_eq!(dial_dijkstra(&integer, 0), expected);
+}
+
+#[test]
+fn test_quantized_weights_within_error_bound() {
+    let graph = Graph::generate_random(200, 0.05, 10.0);
+    let scale = 1000.0;
+    let integer = IntegerGraph::quantize(&graph, scale);
+    let expected = dijkstra(&graph, 0);
+    // Shortest paths have at most n - 1 edges
+    let tolerance = (graph.n - 1) as f64 * 0.5 / scale;
+
+    for dist in [radix_heap_dijkstra(&integer, 0), dial_dijkstra(&integer, 0)] {
+        for (i, (&d, &e)) in dist.iter().zip(&expected).enumerate() {
+            if e.is_finite() {
+                assert!((d - e).abs() <= tolerance, "Distance mismatch at vertex {}: {} vs {}", i, d, e);
+            } else {
+                assert!(d.is_infinite());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_dial_falls_back_on_large_weights() {
+    assert!(std::panic::catch_unwind(|| DialQueue::with_max_step(u64::MAX)).is_err());
+    assert!(DialQueue::with_max_step(MAX_DIAL_STEP).is_empty());
+
+    // A fine scale gives weights far above the bucket limit
+    let graph = Graph::generate_random(100, 0.05, 10.0);
+    let integer = IntegerGraph::quantize(&graph, 1e9);
+    assert!(integer.max_weight > MAX_DIAL_STEP);
+    assert_eq!(dial_dijkstra(&integer, 0), radix_heap_dijkstra(&integer, 0));
+}
+
+#[test]
+fn test_distance_overflow_saturates() {
+    // The second edge would push the distance past u64::MAX
+    let graph = IntegerGraph {
+        n: 3,
+        edges: vec![vec![(1, u64::MAX - 1)], vec![(2, 5)], vec![]],
+        scale: 1.0,
+        max_weight: u64::MAX - 1,
+    };
+    let expected = vec![0.0, (u64::MAX - 1) as f64, f64::INFINITY];
+
+    assert_eq!(dijkstra_with_queue::<BinaryHeapQueue>(&graph, 0), expected);
+    assert_eq!(radix_heap_dijkstra(&graph, 0), expected);
+    assert_eq!(dial_dijkstra(&graph, 0), expected);
+}
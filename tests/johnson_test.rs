@@ -0,0 +1,106 @@
+use shortest_path_validation::graph::{Graph, WeightedGraph};
+use shortest_path_validation::bellman_ford::bellman_ford;
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::johnson::Johnson;
+use shortest_path_validation::solver::Solver;
+
+fn assert_same(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (i, (&a, &e)) in actual.iter().zip(expected).enumerate() {
+        if e.is_finite() {
+            assert!((a - e).abs() < 1e-9, "Distance mismatch at vertex {}: {} vs {}", i, a, e);
+        } else {
+            assert!(a.is_infinite(), "Vertex {} should be unreachable, got {}", i, a);
+        }
+    }
+}
+
+/// Random graph with some negative edges but no negative cycles: weights
+/// are w + p(u) - p(v) for a random potential p
+fn negative_graph(n: usize, density: f64) -> Graph {
+    let base = Graph::generate_random(n, density, 100.0);
+    let p: Vec<f64> = (0..n).map(|v| ((v * 37) % 53) as f64).collect();
+    let mut graph = Graph::new(n);
+    for (u, edges) in base.edges.iter().enumerate() {
+        for edge in edges {
+            graph.add_edge(u, edge.to, edge.weight + p[u] - p[edge.to]);
+        }
+    }
+    graph
+}
+
+#[test]
+fn test_matches_dijkstra_on_non_negative_graphs() {
+    let graph = Graph::generate_random(60, 0.1, 100.0);
+    let matrix = Johnson::new(&graph, Solver::Dijkstra).unwrap().matrix(false);
+
+    for (s, row) in matrix.iter().enumerate() {
+        assert_same(row, &dijkstra(&graph, s));
+    }
+}
+
+#[test]
+fn test_negative_weights_match_bellman_ford() {
+    let graph = negative_graph(50, 0.1);
+    assert!(graph.has_negative_weights());
+
+    for &solver in &[Solver::Dijkstra, Solver::ImprovedV2, Solver::Core] {
+        let johnson = Johnson::new(&graph, solver).unwrap();
+        for s in 0..graph.n {
+            assert_same(&johnson.row(s), &bellman_ford(&graph, s).unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_every_solver_gives_the_same_matrix() {
+    let graph = negative_graph(30, 0.15);
+    let expected = Johnson::new(&graph, Solver::Dijkstra).unwrap().matrix(false);
+
+    for &solver in Solver::ALL {
+        let matrix = Johnson::new(&graph, solver).unwrap().matrix(false);
+        for (row, expected_row) in matrix.iter().zip(&expected) {
+            assert_same(row, expected_row);
+        }
+    }
+}
+
+#[test]
+fn test_parallel_matrix() {
+    let graph = negative_graph(40, 0.1);
+    let johnson = Johnson::new(&graph, Solver::Dijkstra).unwrap();
+    assert_eq!(johnson.matrix(true), johnson.matrix(false));
+}
+
+#[test]
+fn test_negative_cycle_is_rejected() {
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(1, 2, -3.0);
+    graph.add_edge(2, 0, 1.0);
+
+    let cycle = Johnson::new(&graph, Solver::Dijkstra).err().expect("negative cycle");
+    assert!(cycle.weight(&graph) < 0.0);
+}
+
+#[test]
+fn test_write_rows() {
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1, 2.0);
+    graph.add_edge(1, 2, -1.0);
+
+    let johnson = Johnson::new(&graph, Solver::Dijkstra).unwrap();
+    for parallel in [false, true] {
+        let mut out = Vec::new();
+        johnson.write_rows(&mut out, parallel).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "0 2 1\ninf 0 -1\ninf inf 0\n");
+    }
+}
+
+#[test]
+fn test_solver_names_round_trip() {
+    for &solver in Solver::ALL {
+        assert_eq!(Solver::from_name(solver.name()), Some(solver));
+    }
+    assert_eq!(Solver::from_name("unknown"), None);
+}
@@ -0,0 +1,106 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::core_algorithm::{CoreAlgorithm, CoreSolver};
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::parallel::thread_pool;
+use shortest_path_validation::parallel_core::ParallelCoreAlgorithm;
+use std::collections::HashSet;
+
+#[test]
+fn test_parameters_match_sequential() {
+    for n in [100, 1000, 10000] {
+        let graph = Graph::new(n);
+        let sequential = CoreAlgorithm::new(graph.clone());
+        let parallel = ParallelCoreAlgorithm::new(&graph);
+        assert_eq!((parallel.solver.k, parallel.solver.t), sequential.get_params());
+        assert_eq!((parallel.solver.k, parallel.solver.t), (CoreSolver::new(&graph).k, CoreSolver::new(&graph).t));
+    }
+}
+
+#[test]
+fn test_sssp_matches_dijkstra() {
+    for n in [10, 100, 500] {
+        let graph = Graph::generate_random(n, 0.05, 10.0);
+        let expected = dijkstra(&graph, 0);
+
+        for threads in [1, 2, 4] {
+            let pool = thread_pool(threads);
+            let dist = pool.install(|| ParallelCoreAlgorithm::new(&graph).sssp(0));
+            for (v, (&d, &e)) in dist.iter().zip(&expected).enumerate() {
+                if e.is_finite() {
+                    assert!((d - e).abs() < 1e-9, "n={} threads={} vertex {}: {} vs {}", n, threads, v, d, e);
+                } else {
+                    assert!(d.is_infinite(), "n={} threads={} vertex {} should be unreachable", n, threads, v);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_single_source_forks_work() {
+    // One source still goes through FindPivots and splits into concurrent
+    // sub-calls, ending in many base cases rather than a single Dijkstra
+    let graph = Graph::generate_random(2000, 0.005, 10.0);
+    let pool = thread_pool(4);
+    let (dist, counts) = pool.install(|| ParallelCoreAlgorithm::new(&graph).sssp_with_counts(0));
+
+    assert!(counts.joins >= 1, "{:?}", counts);
+    assert!(counts.base_cases > 1, "{:?}", counts);
+    assert_eq!(dist.iter().filter(|d| d.is_finite()).count(), dijkstra(&graph, 0).iter().filter(|d| d.is_finite()).count());
+}
+
+#[test]
+fn test_concurrent_base_cases_identical_to_sequential() {
+    // Level 0 runs one bounded exploration per source; concurrently they
+    // must reach the same fixpoint as running them one after another
+    let graph = Graph::generate_random(300, 0.03, 10.0);
+    let sources = vec![0, 7, 42, 99];
+    let bound = 25.0;
+
+    let mut sequential = CoreAlgorithm::new(graph.clone());
+    for (i, &s) in sources.iter().enumerate() {
+        sequential.dist[s] = i as f64;
+    }
+    sequential.bmssp(0, bound, sources.iter().copied().collect::<HashSet<_>>());
+
+    let pool = thread_pool(4);
+    let parallel = ParallelCoreAlgorithm::new(&graph);
+    for (i, &s) in sources.iter().enumerate() {
+        parallel.dist.relax(s, i as f64);
+    }
+    pool.install(|| parallel.bmssp(0, bound, sources));
+
+    assert_eq!(parallel.dist.into_vec(), sequential.dist);
+}
+
+#[test]
+fn test_find_pivots_behavior() {
+    // Same hub structure as the sequential FindPivots test
+    let mut graph = Graph::new(10);
+    for i in 2..8 {
+        graph.add_edge(1, i, 1.0);
+    }
+    graph.add_edge(8, 9, 1.0);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(0, 8, 1.0);
+
+    let algo = ParallelCoreAlgorithm::new(&graph);
+    algo.dist.relax(0, 0.0);
+    algo.dist.relax(1, 1.0);
+    algo.dist.relax(8, 1.0);
+
+    let (pivots, reachable) = thread_pool(4).install(|| algo.find_pivots(f64::INFINITY, &[1, 8]));
+
+    assert_eq!(pivots, vec![1], "Vertex 1 should be the only pivot (hub vertex)");
+    let mut reachable = reachable;
+    reachable.sort_unstable();
+    assert_eq!(reachable, vec![2, 3, 4, 5, 6, 7, 9]);
+
+    // Pivots come out in discovery order, as in the sequential version
+    let mut sequential = CoreAlgorithm::new(graph.clone());
+    sequential.dist[0] = 0.0;
+    sequential.dist[1] = 1.0;
+    sequential.dist[8] = 1.0;
+    let (expected, _) = sequential.find_pivots(f64::INFINITY, &[1, 8].into_iter().collect());
+    assert_eq!(pivots.into_iter().collect::<HashSet<_>>(), expected);
+}
@@ -0,0 +1,117 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::bellman_ford::bellman_ford;
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::csr::CsrGraph;
+use shortest_path_validation::delta_stepping::auto_delta;
+use shortest_path_validation::parallel::{parallel_bellman_ford, parallel_delta_stepping, thread_pool, AtomicDistances};
+
+fn assert_same(actual: &[f64], expected: &[f64]) {
+    for (i, (&a, &e)) in actual.iter().zip(expected).enumerate() {
+        if e.is_finite() {
+            assert!((a - e).abs() < 1e-9, "Distance mismatch at vertex {}: {} vs {}", i, a, e);
+        } else {
+            assert!(a.is_infinite(), "Vertex {} should be unreachable", i);
+        }
+    }
+}
+
+#[test]
+fn test_csr_layout() {
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(0, 2, 4.0);
+    graph.add_edge(2, 1, 2.0);
+
+    let csr = CsrGraph::from_graph(&graph);
+    assert_eq!(csr.offsets, vec![0, 2, 2, 3]);
+    assert_eq!(csr.m(), 3);
+    assert_eq!(csr.neighbors(0).collect::<Vec<_>>(), vec![(1, 1.0), (2, 4.0)]);
+    assert_eq!(csr.neighbors(1).count(), 0);
+}
+
+#[test]
+fn test_atomic_distances() {
+    let dist = AtomicDistances::new(2);
+    assert!(dist.get(0).is_infinite());
+    assert!(dist.relax(0, 3.0));
+    assert!(!dist.relax(0, 5.0));
+    assert!(dist.relax(0, 0.0));
+    assert_eq!(dist.into_vec()[0], 0.0);
+}
+
+#[test]
+fn test_matches_dijkstra_across_thread_counts() {
+    let graph = Graph::generate_random(300, 0.03, 100.0);
+    let csr = CsrGraph::from_graph(&graph);
+    let expected = dijkstra(&graph, 0);
+    let delta = auto_delta(&graph);
+
+    for threads in [1, 2, 4] {
+        let pool = thread_pool(threads);
+        assert_same(&pool.install(|| parallel_delta_stepping(&csr, 0, delta)), &expected);
+        assert_same(&pool.install(|| parallel_delta_stepping(&csr, 0, 1.0)), &expected);
+        assert_same(&pool.install(|| parallel_bellman_ford(&csr, 0)).unwrap(), &expected);
+    }
+}
+
+#[test]
+fn test_geometric_graph() {
+    let graph = Graph::generate_geometric(400, 0.1);
+    let csr = CsrGraph::from_graph(&graph);
+    let expected = dijkstra(&graph, 5);
+
+    let pool = thread_pool(4);
+    assert_same(&pool.install(|| parallel_delta_stepping(&csr, 5, auto_delta(&graph))), &expected);
+    assert_same(&pool.install(|| parallel_bellman_ford(&csr, 5)).unwrap(), &expected);
+}
+
+#[test]
+fn test_negative_weights() {
+    // 0 -> 1 -> 2 is shorter through the negative edge, and 3 ends up
+    // below zero
+    let mut graph = Graph::new(4);
+    graph.add_edge(0, 1, 2.0);
+    graph.add_edge(1, 2, -1.0);
+    graph.add_edge(0, 2, 3.0);
+    graph.add_edge(2, 3, -4.0);
+    let csr = CsrGraph::from_graph(&graph);
+
+    let pool = thread_pool(2);
+    assert_eq!(pool.install(|| parallel_bellman_ford(&csr, 0)).unwrap(), vec![0.0, 2.0, 1.0, -3.0]);
+    assert_eq!(parallel_bellman_ford(&csr, 0).unwrap(), bellman_ford(&graph, 0).unwrap());
+    assert!(std::panic::catch_unwind(|| parallel_delta_stepping(&csr, 0, 1.0)).is_err());
+
+    // Zero weights are fine
+    let mut graph = Graph::new(2);
+    graph.add_edge(0, 1, 0.0);
+    assert_eq!(parallel_bellman_ford(&CsrGraph::from_graph(&graph), 0).unwrap(), vec![0.0, 0.0]);
+}
+
+#[test]
+fn test_negative_cycle_reported() {
+    let mut graph = Graph::new(4);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(1, 2, -2.0);
+    graph.add_edge(2, 1, 1.0);
+    graph.add_edge(2, 3, 1.0);
+    let csr = CsrGraph::from_graph(&graph);
+
+    let cycle = thread_pool(2).install(|| parallel_bellman_ford(&csr, 0)).unwrap_err();
+    assert!(cycle.weight(&graph) < 0.0);
+
+    // Unreachable from the source, so not reported
+    assert!(parallel_bellman_ford(&csr, 3).is_ok());
+}
+
+#[test]
+fn test_atomic_distances_order_negative_values() {
+    let dist = AtomicDistances::new(1);
+    assert!(dist.relax(0, 5.0));
+    assert!(dist.relax(0, -0.5));
+    assert!(!dist.relax(0, 3.0));
+    assert!(dist.relax(0, -7.25));
+    assert!(!dist.relax(0, -7.25));
+    assert!(!dist.relax(0, f64::NAN));
+    assert_eq!(dist.get(0), -7.25);
+    assert_eq!(dist.into_vec(), vec![-7.25]);
+}
@@ -0,0 +1,63 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::reachability::{pruned_sssp, ReachableSubgraph};
+use shortest_path_validation::solver::Solver;
+
+fn assert_same(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (i, (&a, &e)) in actual.iter().zip(expected).enumerate() {
+        if e.is_finite() {
+            assert!((a - e).abs() < 1e-9, "Distance mismatch at vertex {}: {} vs {}", i, a, e);
+        } else {
+            assert!(a.is_infinite(), "Vertex {} should be unreachable, got {}", i, a);
+        }
+    }
+}
+
+#[test]
+fn test_remapping() {
+    // 2 -> 0 -> 3 -> 1, with 4 -> 3 unreachable from 0
+    let mut graph = Graph::new(5);
+    graph.add_edge(2, 0, 1.0);
+    graph.add_edge(0, 3, 2.0);
+    graph.add_edge(3, 1, 3.0);
+    graph.add_edge(4, 3, 1.0);
+
+    let reachable = ReachableSubgraph::build(&graph, 0);
+    assert_eq!(reachable.original, vec![0, 3, 1]);
+    assert_eq!(reachable.index, vec![0, 2, usize::MAX, 1, usize::MAX]);
+    assert_eq!(reachable.graph.n, 3);
+    assert_eq!(reachable.graph.m(), 2);
+
+    assert_eq!(reachable.stats.pruned_vertices(), 2);
+    assert_eq!(reachable.stats.pruned_edges(), 2);
+    assert!((reachable.stats.pruned_fraction() - 0.4).abs() < 1e-12);
+
+    let dist = dijkstra(&reachable.graph, 0);
+    assert_eq!(reachable.expand(&dist), vec![0.0, 5.0, f64::INFINITY, 2.0, f64::INFINITY]);
+}
+
+#[test]
+fn test_pruned_solvers_match_dijkstra() {
+    // Sparse enough that a good share of vertices is unreachable
+    let graph = Graph::generate_random(1000, 0.0015, 100.0);
+    let expected = dijkstra(&graph, 0);
+
+    for &solver in &[Solver::Dijkstra, Solver::ImprovedV2, Solver::Core, Solver::DeltaStepping] {
+        let (dist, stats) = pruned_sssp(&graph, 0, solver);
+        assert_same(&dist, &expected);
+        assert_eq!(stats.reachable_vertices, expected.iter().filter(|d| d.is_finite()).count());
+    }
+}
+
+#[test]
+fn test_coords_follow_vertices() {
+    let graph = Graph::generate_geometric(200, 0.1);
+    let reachable = ReachableSubgraph::build(&graph, 5);
+    let coords = graph.coords.as_ref().unwrap();
+    let sub_coords = reachable.graph.coords.as_ref().unwrap();
+
+    for (new_v, &v) in reachable.original.iter().enumerate() {
+        assert_eq!(sub_coords[new_v], coords[v]);
+    }
+}
@@ -0,0 +1,77 @@
+use shortest_path_validation::dijkstra::{dijkstra, dijkstra_with_counts};
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::improved_sssp_v2::{improved_sssp_v2, improved_sssp_v2_with_counts};
+use shortest_path_validation::regression::{fit, Model, Ranking, Sample};
+
+/// Sweep with a dense and a sparse half, so the models grow differently
+fn sweep(cost: impl Fn(usize, usize) -> f64) -> Vec<Sample> {
+    let mut samples = Vec::new();
+    for &n in &[100, 400, 1600, 6400, 25600] {
+        for m in [4 * n, n * n / 20] {
+            samples.push(Sample { n, m, value: cost(n, m) });
+        }
+    }
+    samples
+}
+
+#[test]
+fn test_exact_models_fit_best() {
+    for &model in Model::ALL {
+        let samples = sweep(|n, m| 3e-5 * model.eval(n, m));
+        let ranking = Ranking::of(&samples).unwrap();
+
+        assert_eq!(ranking.best().model, model);
+        assert!((ranking.best().r_squared - 1.0).abs() < 1e-12);
+        assert!((ranking.best().coefficient / 3e-5 - 1.0).abs() < 1e-9);
+        assert!(ranking.supports(model, 0.0));
+        assert_eq!(ranking.fits.len(), Model::ALL.len());
+        assert!(ranking.fits.windows(2).all(|w| w[0].r_squared >= w[1].r_squared));
+    }
+}
+
+#[test]
+fn test_unsupported_model_is_flagged() {
+    // Cost that is plainly m + n log n with n log n dominating the sparse half
+    let samples = sweep(|n, m| 2.0 * (m as f64 + 50.0 * n as f64 * (n as f64).ln()));
+    let ranking = Ranking::of(&samples).unwrap();
+
+    assert!(!ranking.supports(Model::MLogTwoThirdsN, 0.01));
+    assert!(ranking.supports(Model::MLogTwoThirdsN, 2.0));
+
+    // Noise lowers R² but keeps the true model on top
+    let noisy: Vec<Sample> = sweep(|n, m| Model::MLogN.eval(n, m))
+        .into_iter()
+        .enumerate()
+        .map(|(i, s)| Sample { value: s.value * if i % 2 == 0 { 1.05 } else { 0.95 }, ..s })
+        .collect();
+    let ranking = Ranking::of(&noisy).unwrap();
+    assert_eq!(ranking.best().model, Model::MLogN);
+    assert!(ranking.best().r_squared < 1.0);
+}
+
+#[test]
+fn test_degenerate_samples() {
+    let one = [Sample { n: 10, m: 20, value: 1.0 }];
+    assert!(fit(Model::MLogN, &one).is_none());
+    assert!(Ranking::of(&[]).is_none());
+
+    let constant = [Sample { n: 10, m: 20, value: 1.0 }, Sample { n: 20, m: 40, value: 1.0 }];
+    assert!(Ranking::of(&constant).is_none());
+}
+
+#[test]
+fn test_operation_counts() {
+    let graph = Graph::generate_random(300, 0.05, 100.0);
+    let (dist, counts) = dijkstra_with_counts(&graph, 0);
+
+    assert_eq!(dist, dijkstra(&graph, 0));
+    assert!(counts.relaxations as usize <= graph.m());
+    // Every pushed entry is popped, stale ones included
+    assert_eq!(counts.heap_pushes, counts.heap_pops);
+    assert!(counts.heap_pops as usize >= dist.iter().filter(|d| d.is_finite()).count());
+    assert_eq!(counts.total(), counts.relaxations + counts.heap_pushes + counts.heap_pops);
+
+    let (dist, counts) = improved_sssp_v2_with_counts(&graph, 0);
+    assert_eq!(dist, improved_sssp_v2(&graph, 0));
+    assert!(counts.relaxations > 0);
+}
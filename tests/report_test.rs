@@ -0,0 +1,158 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::report::{parse_csv, renderer, Format, Record, CSV_HEADER};
+
+fn records() -> Vec<Record> {
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(1, 2, 1.0);
+
+    let mut a = Record::new("random:n=3,density=0.5", &graph, "dijkstra", 0, 2.0);
+    a.setup_ms = 0.5;
+    a.counters.push(("reachable".to_string(), 3));
+    let mut b = Record::new("random:n=3,density=0.5", &graph, "core", 0, 4.0);
+    b.max_error = Some(f64::INFINITY);
+    vec![a, b]
+}
+
+fn render(format: Format, records: &[Record]) -> String {
+    let mut buffer = Vec::new();
+    {
+        let mut out = renderer(format, &mut buffer);
+        for r in records {
+            out.record(r).unwrap();
+        }
+        out.finish().unwrap();
+    }
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn test_record_fields() {
+    let r = &records()[0];
+    assert_eq!((r.n, r.m), (3, 2));
+    assert!((r.density - 2.0 / 6.0).abs() < 1e-12);
+    assert_eq!(r.counter("reachable"), Some(3));
+    assert_eq!(r.counter("threads"), None);
+}
+
+#[test]
+fn test_json_lines() {
+    let output = render(Format::Json, &records());
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("{\"graph\":\"random:n=3,density=0.5\",\"n\":3,\"m\":2,"));
+    assert!(lines[0].contains("\"solver\":\"dijkstra\""));
+    assert!(lines[0].contains("\"time_ms\":2,\"setup_ms\":0.5,"));
+    assert!(lines[0].ends_with("\"max_error\":null,\"counters\":{\"reachable\":3}}"));
+    // JSON has no infinity
+    assert!(lines[1].contains("\"max_error\":null"));
+}
+
+#[test]
+fn test_csv() {
+    let output = render(Format::Csv, &records());
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], CSV_HEADER);
+    // The graph id contains a comma, so it is quoted
+    assert!(lines[1].starts_with("\"random:n=3,density=0.5\",3,2,"));
+    assert!(lines[1].ends_with(",dijkstra,0,0,2,0.5,,reachable=3"));
+    assert!(lines[2].ends_with(",core,0,0,4,0,inf,"));
+}
+
+#[test]
+fn test_csv_round_trip() {
+    let mut records = records();
+    records[1].graph = "file:path=a \"b\".gr".to_string();
+    records[1].counters = vec![("reachable".to_string(), 2), ("threads".to_string(), 4)];
+    let output = render(Format::Csv, &records);
+
+    assert_eq!(parse_csv(output.as_bytes()).unwrap(), records);
+    assert!(parse_csv("".as_bytes()).is_err());
+    assert!(parse_csv("graph,n\n".as_bytes()).is_err());
+
+    let bad = format!("{}\ng,1,2\n", CSV_HEADER);
+    assert!(parse_csv(bad.as_bytes()).is_err());
+    let bad = format!("{}\ng,x,1,0.1,dijkstra,0,0,1,0,0,\n", CSV_HEADER);
+    assert!(parse_csv(bad.as_bytes()).unwrap_err().to_string().contains("line 2"));
+}
+
+#[test]
+fn test_table_groups_by_graph() {
+    let mut input = records();
+    let mut other = input[0].clone();
+    other.graph = "other".to_string();
+    input.push(other);
+
+    let output = render(Format::Table, &input);
+    assert_eq!(output.matches("Graph: ").count(), 2);
+    assert!(output.contains("Graph: random:n=3,density=0.5 (3 vertices, 2 edges, 1 sources)"));
+    assert!(output.contains("0.50x"));
+    assert!(output.contains("WARNING: core results differ by inf"));
+}
+
+#[test]
+fn test_table_unchecked_diff() {
+    // Runs with check off carry no error, so the table shows "-" rather
+    // than a zero difference
+    let mut input = records();
+    input[1].max_error = Some(0.0);
+
+    let output = render(Format::Table, &input);
+    let row = |solver: &str| output.lines().find(|l| l.starts_with(solver)).unwrap().split_whitespace().nth(6).unwrap().to_string();
+    assert_eq!(row("dijkstra"), "-");
+    assert_eq!(row("core"), "0.00e0");
+    assert!(!output.contains("WARNING"));
+}
+
+#[test]
+fn test_table_memory_columns() {
+    let output = render(Format::Table, &records());
+    assert!(!output.contains("Peak (KiB)"));
+
+    let mut records = records();
+    for (r, peak) in records.iter_mut().zip([2048u64, 4096]) {
+        r.counters.push(("allocations".to_string(), 3));
+        r.counters.push(("alloc_bytes".to_string(), 3 * peak));
+        r.counters.push(("peak_bytes".to_string(), peak));
+    }
+    let output = render(Format::Table, &records);
+    assert!(output.contains("Peak (KiB)"));
+    let core = output.lines().find(|l| l.starts_with("core")).unwrap();
+    assert!(core.contains(" 4.0 ") && core.contains(" 12.0 "));
+}
+
+#[test]
+fn test_format_names() {
+    assert_eq!(Format::from_name("json"), Some(Format::Json));
+    assert_eq!(Format::from_name("jsonl"), Some(Format::Json));
+    assert_eq!(Format::from_name("csv"), Some(Format::Csv));
+    assert_eq!(Format::from_name("table"), Some(Format::Table));
+    assert_eq!(Format::from_name("xml"), None);
+}
+
+#[test]
+fn test_table_statistics() {
+    let graph = Graph::new(2);
+    let input: Vec<Record> = [1.0, 2.0, 3.0, 4.0, 100.0]
+        .iter()
+        .enumerate()
+        .map(|(rep, &t)| {
+            let mut r = Record::new("g", &graph, "dijkstra", 0, t);
+            r.rep = rep;
+            r.setup_ms = 0.25;
+            r
+        })
+        .collect();
+
+    let output = render(Format::Table, &input);
+    let row = output.lines().find(|l| l.starts_with("dijkstra")).unwrap();
+    let fields: Vec<&str> = row.split_whitespace().collect();
+    // Runs, median, p90
+    assert_eq!(&fields[1..4], &["5", "3.000", "61.600"]);
+    assert!(row.contains("0.250"));
+    // The outlier makes the interval wide
+    assert!(output.contains("NOTE: dijkstra timings are noisy"));
+}
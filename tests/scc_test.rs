@@ -0,0 +1,103 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::scc::{condensation_sssp, kosaraju_scc, tarjan_scc, Components, Condensation};
+
+fn assert_same(actual: &[f64], expected: &[f64]) {
+    for (i, (&a, &e)) in actual.iter().zip(expected).enumerate() {
+        if e.is_finite() {
+            assert!((a - e).abs() < 1e-9, "Distance mismatch at vertex {}: {} vs {}", i, a, e);
+        } else {
+            assert!(a.is_infinite(), "Vertex {} should be unreachable, got {}", i, a);
+        }
+    }
+}
+
+/// Checks that component ids are a topological order of the condensation
+fn assert_topological(graph: &Graph, components: &Components) {
+    for (u, edges) in graph.edges.iter().enumerate() {
+        for edge in edges {
+            assert!(components.component[u] <= components.component[edge.to],
+                    "edge {} -> {} goes backwards between components", u, edge.to);
+        }
+    }
+}
+
+/// Two cycles {0, 1, 2} and {3, 4} joined by 2 -> 3, plus isolated 5
+fn two_cycles() -> Graph {
+    let mut graph = Graph::new(6);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(1, 2, 1.0);
+    graph.add_edge(2, 0, 1.0);
+    graph.add_edge(2, 3, 4.0);
+    graph.add_edge(1, 3, 7.0);
+    graph.add_edge(3, 4, 1.0);
+    graph.add_edge(4, 3, 1.0);
+    graph
+}
+
+#[test]
+fn test_known_components() {
+    let graph = two_cycles();
+    for components in [tarjan_scc(&graph), kosaraju_scc(&graph)] {
+        assert_eq!(components.count, 3);
+        let c = &components.component;
+        assert!(c[0] == c[1] && c[1] == c[2]);
+        assert_eq!(c[3], c[4]);
+        assert!(c[2] < c[3]);
+        assert_topological(&graph, &components);
+
+        let mut sizes = components.sizes();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 2, 3]);
+    }
+}
+
+#[test]
+fn test_tarjan_and_kosaraju_agree() {
+    for &density in &[0.001, 0.003, 0.01] {
+        let graph = Graph::generate_random(1000, density, 10.0);
+        let tarjan = tarjan_scc(&graph);
+        let kosaraju = kosaraju_scc(&graph);
+
+        assert_eq!(tarjan.count, kosaraju.count);
+        assert_topological(&graph, &tarjan);
+        assert_topological(&graph, &kosaraju);
+
+        // Same partition: u and v share a component in both or in neither
+        let mut map = vec![usize::MAX; tarjan.count];
+        for (v, &c) in tarjan.component.iter().enumerate() {
+            if map[c] == usize::MAX {
+                map[c] = kosaraju.component[v];
+            }
+            assert_eq!(map[c], kosaraju.component[v]);
+        }
+    }
+}
+
+#[test]
+fn test_condensation_dag() {
+    let graph = two_cycles();
+    let condensation = Condensation::build(&graph);
+
+    assert_eq!(condensation.dag.n, 3);
+    assert!(condensation.dag.is_acyclic());
+    // The two edges 1 -> 3 and 2 -> 3 collapse into one with the lighter weight
+    assert_eq!(condensation.cross_edges(), 1);
+    let c = &condensation.components.component;
+    let edge = &condensation.dag.edges[c[0]][0];
+    assert_eq!((edge.to, edge.weight), (c[3], 4.0));
+}
+
+#[test]
+fn test_condensation_sssp_matches_dijkstra() {
+    for &density in &[0.001, 0.003, 0.01] {
+        let graph = Graph::generate_random(1000, density, 100.0);
+        for source in [0, 500] {
+            assert_same(&condensation_sssp(&graph, source), &dijkstra(&graph, source));
+        }
+    }
+
+    let graph = two_cycles();
+    assert_eq!(condensation_sssp(&graph, 0), vec![0.0, 1.0, 2.0, 6.0, 7.0, f64::INFINITY]);
+    assert_eq!(condensation_sssp(&graph, 3), vec![f64::INFINITY, f64::INFINITY, f64::INFINITY, 0.0, 1.0, f64::INFINITY]);
+}
@@ -0,0 +1,65 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::dijkstra::dijkstra;
+use shortest_path_validation::solver::Solver;
+use shortest_path_validation::stats::{percentile, t_quantile_975, Summary};
+
+#[test]
+fn test_summary() {
+    let s = Summary::of(&[5.0, 1.0, 3.0, 2.0, 4.0]).unwrap();
+    assert_eq!(s.count, 5);
+    assert_eq!((s.min, s.median, s.max), (1.0, 3.0, 5.0));
+    assert!((s.mean - 3.0).abs() < 1e-12);
+    assert!((s.std_dev - 2.5f64.sqrt()).abs() < 1e-12);
+    assert!((s.p90 - 4.6).abs() < 1e-12);
+    // t(4) = 2.776
+    assert!((s.ci95 - 2.776 * 2.5f64.sqrt() / 5f64.sqrt()).abs() < 1e-9);
+    assert!((s.relative_ci() - s.ci95 / 3.0).abs() < 1e-12);
+
+    assert!(Summary::of(&[]).is_none());
+    let single = Summary::of(&[7.0]).unwrap();
+    assert_eq!((single.median, single.std_dev), (7.0, 0.0));
+    assert!(single.ci95.is_infinite());
+}
+
+#[test]
+fn test_percentile() {
+    let sorted = [10.0, 20.0, 30.0, 40.0];
+    assert_eq!(percentile(&sorted, 0.0), 10.0);
+    assert_eq!(percentile(&sorted, 100.0), 40.0);
+    assert_eq!(percentile(&sorted, 50.0), 25.0);
+    assert_eq!(percentile(&[3.0], 90.0), 3.0);
+}
+
+#[test]
+fn test_t_quantiles() {
+    assert_eq!(t_quantile_975(1), 12.706);
+    assert_eq!(t_quantile_975(30), 2.042);
+    assert_eq!(t_quantile_975(40), 2.021);
+    assert_eq!(t_quantile_975(60), 2.000);
+    assert_eq!(t_quantile_975(120), 1.980);
+    // Between table rows, close to the exact quantiles
+    for (df, exact) in [(31, 2.0395), (45, 2.0141), (80, 1.9901), (1000, 1.9623)] {
+        assert!((t_quantile_975(df) - exact).abs() < 1e-3, "df {}: {}", df, t_quantile_975(df));
+    }
+    assert!((t_quantile_975(usize::MAX) - 1.960).abs() < 1e-12);
+    assert!(t_quantile_975(0).is_infinite());
+    // Decreasing towards the normal quantile
+    assert!((1..200).all(|df| t_quantile_975(df) >= t_quantile_975(df + 1)));
+}
+
+#[test]
+fn test_setup_then_solve_matches_run() {
+    let graph = Graph::generate_random(300, 0.02, 100.0);
+    let expected = dijkstra(&graph, 7);
+
+    for &solver in Solver::ALL {
+        let dist = solver.setup(&graph).solve(7);
+        for (i, (&d, &e)) in dist.iter().zip(&expected).enumerate() {
+            if e.is_finite() {
+                assert!((d - e).abs() < 1e-9, "{}: mismatch at vertex {}", solver.name(), i);
+            } else {
+                assert!(d.is_infinite(), "{}: vertex {} should be unreachable", solver.name(), i);
+            }
+        }
+    }
+}
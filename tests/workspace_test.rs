@@ -0,0 +1,87 @@
+use shortest_path_validation::graph::Graph;
+use shortest_path_validation::dijkstra::{dijkstra, DijkstraWorkspace};
+use shortest_path_validation::core_algorithm::{CoreAlgorithm, CoreSolver, CoreWorkspace};
+use shortest_path_validation::workspace::{EpochSet, EpochVec};
+
+fn assert_same(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (i, (&a, &e)) in actual.iter().zip(expected).enumerate() {
+        if e.is_finite() {
+            assert!((a - e).abs() < 1e-9, "Distance mismatch at vertex {}: {} vs {}", i, a, e);
+        } else {
+            assert!(a.is_infinite(), "Vertex {} should be unreachable, got {}", i, a);
+        }
+    }
+}
+
+#[test]
+fn test_epoch_reset() {
+    let mut values = EpochVec::new(4, -1i32);
+    values.set(1, 10);
+    values.set(3, 30);
+    assert_eq!(values.to_vec(), vec![-1, 10, -1, 30]);
+    assert!(values.is_set(1) && !values.is_set(0));
+
+    values.reset();
+    assert_eq!(values.to_vec(), vec![-1; 4]);
+    values.set(2, 20);
+    let mut out = [0; 4];
+    values.write_to(&mut out);
+    assert_eq!(out, [-1, -1, 20, -1]);
+
+    let mut set = EpochSet::new(3);
+    assert!(set.insert(2));
+    assert!(!set.insert(2));
+    assert!(set.contains(2) && !set.contains(0));
+    set.clear();
+    assert!(!set.contains(2));
+    assert!(set.insert(2));
+}
+
+#[test]
+fn test_dijkstra_workspace() {
+    // Sparse enough that some sources reach only part of the graph
+    for &density in &[0.002, 0.05] {
+        let graph = Graph::generate_random(500, density, 100.0);
+        let mut workspace = DijkstraWorkspace::new(graph.n);
+
+        for s in (0..graph.n).step_by(37) {
+            workspace.run(&graph, s);
+            let expected = dijkstra(&graph, s);
+            assert_same(&workspace.distances(), &expected);
+
+            let mut reached = workspace.reached().to_vec();
+            reached.sort_unstable();
+            let finite: Vec<usize> = (0..graph.n).filter(|&v| expected[v].is_finite()).collect();
+            assert_eq!(reached, finite);
+            assert_eq!(workspace.distance(s), 0.0);
+        }
+    }
+}
+
+#[test]
+fn test_core_workspace() {
+    for &density in &[0.002, 0.05] {
+        let graph = Graph::generate_random(500, density, 100.0);
+        let solver = CoreSolver::new(&graph);
+        let mut workspace = CoreWorkspace::new(graph.n);
+
+        for s in (0..graph.n).step_by(37) {
+            workspace.run(&solver, s);
+            assert_same(&workspace.distances(), &dijkstra(&graph, s));
+            assert_same(&workspace.distances(), &CoreAlgorithm::new(graph.clone()).sssp(s));
+        }
+    }
+
+    // A smaller graph fits in a larger workspace
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1, 1.0);
+    graph.add_edge(1, 2, 1.0);
+    let mut workspace = CoreWorkspace::new(10);
+    workspace.run(&CoreSolver::new(&graph), 1);
+    assert_eq!(workspace.distances(), vec![f64::INFINITY, 0.0, 1.0]);
+
+    let mut workspace = DijkstraWorkspace::new(10);
+    workspace.run(&graph, 1);
+    assert_eq!(workspace.distances(), vec![f64::INFINITY, 0.0, 1.0]);
+}